@@ -0,0 +1,4 @@
+-- Organization member roles
+ALTER TYPE role ADD VALUE IF NOT EXISTS 'admin';
+ALTER TYPE role ADD VALUE IF NOT EXISTS 'manager';
+ALTER TYPE role ADD VALUE IF NOT EXISTS 'member';
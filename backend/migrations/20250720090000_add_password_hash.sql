@@ -0,0 +1,2 @@
+-- First-party credentials
+ALTER TABLE users ADD COLUMN password_hash TEXT;
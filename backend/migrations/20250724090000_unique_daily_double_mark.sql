@@ -0,0 +1,4 @@
+-- One DoubleMark record per user, organization and day
+CREATE UNIQUE INDEX attendances_double_mark_daily_idx
+  ON attendances (user_id, organization_id, date)
+  WHERE attendance_type = 'doublemark';
@@ -0,0 +1,27 @@
+CREATE TYPE geofence_policy AS ENUM ('flag', 'reject');
+
+ALTER TABLE organizations
+  ADD COLUMN geofence_policy geofence_policy NOT NULL DEFAULT 'flag';
+
+-- Geofences
+CREATE TABLE geofences (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  center_lat DOUBLE PRECISION,
+  center_long DOUBLE PRECISION,
+  radius_meters DOUBLE PRECISION,
+  polygon JSONB,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  CHECK (
+    (polygon IS NULL AND center_lat IS NOT NULL AND center_long IS NOT NULL AND radius_meters > 0)
+    OR (polygon IS NOT NULL AND center_lat IS NULL AND center_long IS NULL AND radius_meters IS NULL)
+  )
+);
+
+CREATE INDEX geofences_organization_id_idx ON geofences (organization_id);
+
+ALTER TABLE attendances
+  ADD COLUMN distance_meters DOUBLE PRECISION,
+  ADD COLUMN outside_geofence BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,9 @@
+-- Each signed QR code can be redeemed once per user
+CREATE TABLE qr_code_redemptions (
+  code TEXT NOT NULL,
+  user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  redeemed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  PRIMARY KEY (code, user_id)
+);
+
+CREATE INDEX qr_code_redemptions_redeemed_at_idx ON qr_code_redemptions (redeemed_at);
@@ -0,0 +1,28 @@
+CREATE TYPE device_kind AS ENUM ('kiosk', 'nfcreader', 'biometric');
+
+-- Check-in devices
+CREATE TABLE devices (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  kind device_kind NOT NULL,
+  api_key_hash TEXT NOT NULL UNIQUE,
+  last_seen_at TIMESTAMPTZ,
+  revoked_at TIMESTAMPTZ,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX devices_organization_id_idx ON devices (organization_id);
+
+-- Badge UID to user mapping
+CREATE TABLE badges (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  uid TEXT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  UNIQUE (organization_id, uid)
+);
+
+CREATE INDEX badges_user_id_idx ON badges (user_id);
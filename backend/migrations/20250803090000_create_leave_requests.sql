@@ -0,0 +1,24 @@
+CREATE TYPE leave_type AS ENUM ('sick', 'vacation', 'personal', 'other');
+CREATE TYPE leave_status AS ENUM ('pending', 'approved', 'rejected', 'cancelled');
+
+-- Leave and excuse requests
+CREATE TABLE leave_requests (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  leave_type leave_type NOT NULL,
+  start_date DATE NOT NULL,
+  end_date DATE NOT NULL,
+  reason TEXT NOT NULL,
+  attachment_url TEXT,
+  status leave_status NOT NULL DEFAULT 'pending',
+  reviewed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+  reviewed_at TIMESTAMPTZ,
+  review_note TEXT,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  CHECK (end_date >= start_date)
+);
+
+CREATE INDEX leave_requests_organization_id_idx ON leave_requests (organization_id, status);
+CREATE INDEX leave_requests_user_dates_idx ON leave_requests (user_id, start_date, end_date);
@@ -0,0 +1,34 @@
+CREATE TYPE correction_status AS ENUM ('pending', 'approved', 'rejected', 'cancelled');
+
+-- Proposed changes to recorded attendances, applied only after review
+CREATE TABLE attendance_corrections (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+  attendance_id UUID NOT NULL REFERENCES attendances(id) ON DELETE CASCADE,
+  requested_by UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  original_clock_in TIMESTAMPTZ,
+  original_clock_out TIMESTAMPTZ,
+  original_status attendance_status NOT NULL,
+  proposed_clock_in TIMESTAMPTZ,
+  proposed_clock_out TIMESTAMPTZ,
+  proposed_status attendance_status,
+  reason TEXT NOT NULL,
+  status correction_status NOT NULL DEFAULT 'pending',
+  reviewed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+  reviewed_at TIMESTAMPTZ,
+  review_note TEXT,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  CHECK (
+    proposed_clock_in IS NOT NULL
+    OR proposed_clock_out IS NOT NULL
+    OR proposed_status IS NOT NULL
+  )
+);
+
+CREATE INDEX attendance_corrections_organization_id_idx ON attendance_corrections (organization_id, status);
+CREATE INDEX attendance_corrections_attendance_id_idx ON attendance_corrections (attendance_id);
+
+-- At most one open correction per attendance
+CREATE UNIQUE INDEX attendance_corrections_pending_idx
+  ON attendance_corrections (attendance_id) WHERE status = 'pending';
@@ -0,0 +1,33 @@
+-- Organization holiday calendar; recurring entries repeat on the same month and day every year
+CREATE TABLE holidays (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  date DATE NOT NULL,
+  recurring BOOLEAN NOT NULL DEFAULT false,
+  source_uid TEXT,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  UNIQUE (organization_id, date)
+);
+
+CREATE INDEX holidays_recurring_idx
+  ON holidays (organization_id, EXTRACT(MONTH FROM date), EXTRACT(DAY FROM date))
+  WHERE recurring;
+
+CREATE FUNCTION is_holiday(org UUID, day DATE) RETURNS BOOLEAN
+LANGUAGE sql STABLE AS $$
+  SELECT EXISTS (
+    SELECT 1 FROM holidays h
+    WHERE h.organization_id = org
+      AND (
+        h.date = day
+        OR (
+          h.recurring
+          AND h.date <= day
+          AND EXTRACT(MONTH FROM h.date) = EXTRACT(MONTH FROM day)
+          AND EXTRACT(DAY FROM h.date) = EXTRACT(DAY FROM day)
+        )
+      )
+  )
+$$;
@@ -0,0 +1,14 @@
+-- IANA timezone used for every day boundary of the organization
+ALTER TABLE organizations ADD COLUMN timezone TEXT NOT NULL DEFAULT 'UTC';
+
+-- Calendar day of the attendance in the organization's timezone
+ALTER TABLE attendances ADD COLUMN local_date DATE;
+UPDATE attendances SET local_date = (date AT TIME ZONE 'UTC')::date;
+ALTER TABLE attendances ALTER COLUMN local_date SET NOT NULL;
+
+DROP INDEX attendances_double_mark_daily_idx;
+CREATE UNIQUE INDEX attendances_double_mark_daily_idx
+  ON attendances (user_id, organization_id, local_date)
+  WHERE attendance_type = 'doublemark';
+
+CREATE INDEX attendances_organization_local_date_idx ON attendances (organization_id, local_date);
@@ -0,0 +1,3 @@
+-- Paginated listings filter by tenant and sort by name or user
+CREATE INDEX users_organization_name_idx ON users (organization_id, name, id);
+CREATE INDEX attendances_user_local_date_idx ON attendances (user_id, local_date);
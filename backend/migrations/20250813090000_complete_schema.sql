@@ -0,0 +1,6 @@
+-- Users are created before the organization they register, so users.organization_id
+-- cannot reference organizations; the owner link is enforced instead
+ALTER TABLE organizations
+  ADD CONSTRAINT organizations_owner_id_fkey FOREIGN KEY (owner_id) REFERENCES users (id);
+
+CREATE INDEX organizations_owner_id_idx ON organizations (owner_id);
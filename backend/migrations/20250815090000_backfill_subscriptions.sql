@@ -0,0 +1,13 @@
+-- Free plans never expire; NULL marks "no expiry"
+ALTER TABLE subscriptions ALTER COLUMN expiry_date DROP NOT NULL;
+UPDATE subscriptions SET expiry_date = NULL WHERE plan = 'free';
+
+-- Owners registered before subscriptions were managed start on the free plan
+INSERT INTO subscriptions (user_id, plan, status, start_date, expiry_date)
+SELECT DISTINCT owner_id, 'free'::subscription_plan, 'active'::subscription_status, now(), NULL
+FROM organizations
+ON CONFLICT (user_id) DO NOTHING;
+
+-- Lapsing paid subscriptions are scanned periodically
+CREATE INDEX subscriptions_lapsing_idx ON subscriptions (expiry_date)
+  WHERE plan <> 'free' AND status IN ('active', 'graceperiod');
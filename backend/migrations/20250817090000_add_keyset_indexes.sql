@@ -0,0 +1,4 @@
+-- Keyset pages seek on (sort column, id) within a tenant
+DROP INDEX attendances_organization_local_date_idx;
+CREATE INDEX attendances_organization_local_date_id_idx ON attendances (organization_id, local_date, id);
+CREATE INDEX users_organization_created_at_id_idx ON users (organization_id, created_at, id);
@@ -0,0 +1,2 @@
+-- New users start as plain members, matching Role::default()
+ALTER TABLE users ALTER COLUMN role SET DEFAULT 'member';
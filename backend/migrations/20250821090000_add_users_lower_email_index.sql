@@ -0,0 +1,2 @@
+-- Sign-in looks users up by lower(email)
+CREATE INDEX users_lower_email_idx ON users (lower(email));
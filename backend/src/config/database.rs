@@ -1,5 +1,5 @@
 use log::{error, info};
-use sqlx::{Error as SqlxError, PgPool, postgres::PgPoolOptions};
+use sqlx::{Error as SqlxError, PgPool, migrate::MigrateError, postgres::PgPoolOptions};
 use std::time::Duration;
 
 use crate::constants::DATABASE_URL;
@@ -29,4 +29,11 @@ impl Database {
 
         Ok(Self { pool })
     }
+
+    /// Applies the pending files in `backend/migrations`, which are embedded at compile time.
+    pub async fn migrate(&self) -> Result<(), MigrateError> {
+        sqlx::migrate!("./migrations").run(&self.pool).await?;
+        info!("Database migrations are up to date");
+        Ok(())
+    }
 }
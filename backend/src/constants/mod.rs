@@ -14,6 +14,7 @@ lazy_env_var!(CLIENT_ID);
 lazy_env_var!(CLIENT_SECRET);
 lazy_env_var!(REDIRECT_URI);
 lazy_env_var!(JWT_SECRET_KEY);
+lazy_env_var!(QR_SECRET_KEY);
 lazy_env_var!(COOKIE_NAME);
 lazy_env_var!(DATABASE_URL);
 lazy_env_var!(DB_NAME);
@@ -22,4 +23,13 @@ lazy_env_var!(CLASS_COL_NAME);
 lazy_env_var!(ATTENDANCE_COL_NAME);
 lazy_env_var!(ORGANIZATION_COL_NAME);
 lazy_env_var!(ORGANIZATION_MEMBER_COL_NAME);
-lazy_env_var!(SUPABASE_JWT_SECRET);
+
+/// The refresh token rides in its own cookie next to the access token.
+pub static REFRESH_COOKIE_NAME: LazyLock<String> =
+    LazyLock::new(|| format!("{}_refresh", *COOKIE_NAME));
+
+/// Opt-in, since databases provisioned by hand have no migration history to resume from.
+pub static RUN_MIGRATIONS: LazyLock<bool> = LazyLock::new(|| {
+    env::var("RUN_MIGRATIONS")
+        .is_ok_and(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true"))
+});
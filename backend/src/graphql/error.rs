@@ -1,3 +1,4 @@
+use actix_web::http::StatusCode;
 use async_graphql::{Error as GQLError, ErrorExtensions};
 use thiserror::Error;
 
@@ -6,6 +7,9 @@ pub enum AppError {
     #[error("Unauthorized")]
     Unauthorized,
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("Validation failed: {0}")]
     Validation(String),
 
@@ -19,9 +23,23 @@ pub enum AppError {
     Internal(String),
 }
 
+impl AppError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::Unauthorized => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
 pub fn graphql_error(err: AppError) -> GQLError {
     let code = match &err {
         AppError::Unauthorized => "UNAUTHORIZED",
+        AppError::Forbidden(_) => "FORBIDDEN",
         AppError::Validation(_) => "VALIDATION",
         AppError::NotFound(_) => "NOT_FOUND",
         AppError::Conflict(_) => "CONFLICT",
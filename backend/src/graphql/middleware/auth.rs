@@ -1,38 +1,95 @@
-use crate::constants::SUPABASE_JWT_SECRET;
+use crate::constants::JWT_SECRET_KEY;
+use crate::graphql::context::GQLContext;
 use crate::graphql::error::AppError;
 use crate::graphql::error::graphql_error;
-use async_graphql::{Context, Result};
+use crate::repositories::tenant_scope::TenantScope;
+use async_graphql::{Context, Data, Result};
 use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode};
+use log::debug;
 use serde_json::Value;
+use shared::types::responses::user_response::UserResponse;
+use uuid::Uuid;
 
 pub fn validate_token(token: &str) -> Result<Value, AppError> {
-    let secret = SUPABASE_JWT_SECRET.as_str();
-    println!("[DEBUG] Decoding token with secret: {}", secret);
-
-    let mut validation = Validation::new(Algorithm::HS256);
-    validation.set_audience(&["authenticated"]);
-
     decode::<Value>(
         token,
-        &DecodingKey::from_secret(secret.as_bytes()),
-        &validation,
+        &DecodingKey::from_secret(JWT_SECRET_KEY.as_bytes()),
+        &Validation::new(Algorithm::HS256),
     )
     .map(|data| {
-        println!("[DEBUG] Token valid. Claims: {:?}", data.claims);
+        debug!("Token valid. Claims: {:?}", data.claims);
         data.claims
     })
     .map_err(|err| {
-        println!("[ERROR] Failed to decode token: {}", err);
+        debug!("Failed to decode token: {}", err);
         AppError::Unauthorized
     })
 }
 
 pub fn extract_claims(ctx: &Context<'_>) -> Result<Value> {
     if let Some(token) = ctx.data_opt::<String>() {
-        println!("[DEBUG] JWT token found in context: {}", token);
         return validate_token(token).map_err(graphql_error);
     }
 
-    println!("[DEBUG] No JWT token found in context");
+    debug!("No JWT token found in context");
     Err(graphql_error(AppError::Unauthorized))
 }
+
+/// Refresh token the browser sent back in its httpOnly cookie.
+pub struct RefreshCookie(pub String);
+
+/// An explicit `refreshToken` argument wins over the cookie.
+pub fn refresh_token_from(ctx: &Context<'_>, explicit: Option<String>) -> Option<String> {
+    explicit.or_else(|| {
+        ctx.data_opt::<RefreshCookie>()
+            .map(|cookie| cookie.0.clone())
+    })
+}
+
+pub fn extract_user_id(ctx: &Context<'_>) -> Result<String> {
+    let claims = extract_claims(ctx)?;
+
+    claims
+        .get("sub")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| graphql_error(AppError::Unauthorized))
+}
+
+pub async fn current_user(ctx: &Context<'_>) -> Result<UserResponse> {
+    let user_id = extract_user_id(ctx)?;
+    let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
+
+    svc.get_user_by_id(TenantScope::AllOrganizations, &user_id)
+        .await
+        .map_err(graphql_error)?
+        .ok_or_else(|| graphql_error(AppError::Unauthorized))
+}
+
+pub fn tenant_scope(user: &UserResponse) -> Result<TenantScope, AppError> {
+    let organization_id =
+        Uuid::parse_str(&user.organization_id).map_err(|_| AppError::Unauthorized)?;
+
+    Ok(TenantScope::for_user(user.role, organization_id))
+}
+
+pub async fn current_scope(ctx: &Context<'_>) -> Result<TenantScope> {
+    let user = current_user(ctx).await?;
+    tenant_scope(&user).map_err(graphql_error)
+}
+
+pub async fn on_connection_init(payload: Value) -> Result<Data> {
+    let token = payload
+        .get("Authorization")
+        .or_else(|| payload.get("authorization"))
+        .and_then(|v| v.as_str())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .or_else(|| payload.get("token").and_then(|v| v.as_str()))
+        .ok_or_else(|| graphql_error(AppError::Unauthorized))?;
+
+    validate_token(token).map_err(graphql_error)?;
+
+    let mut data = Data::default();
+    data.insert(token.to_string());
+    Ok(data)
+}
@@ -0,0 +1,57 @@
+use crate::graphql::error::{AppError, graphql_error};
+use crate::graphql::middleware::auth::current_user;
+use async_graphql::{Context, Guard, Result};
+use shared::{
+    models::user_model::NO_ORGANIZATION,
+    types::models::user::{permissions::Permission, role::Role},
+};
+
+/// Permissions only apply within an organization, so callers who have not joined one yet are
+/// refused before they reach a tenant-scoped write.
+pub struct PermissionGuard {
+    permission: Permission,
+}
+
+impl PermissionGuard {
+    pub fn new(permission: Permission) -> Self {
+        Self { permission }
+    }
+}
+
+impl Guard for PermissionGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let user = current_user(ctx).await?;
+
+        if user.role != Role::Superadmin && user.organization_id == NO_ORGANIZATION.to_string() {
+            return Err(graphql_error(AppError::Forbidden(
+                "Caller does not belong to an organization".into(),
+            )));
+        }
+
+        if user.role.has_permission(self.permission) {
+            Ok(())
+        } else {
+            Err(graphql_error(AppError::Forbidden(format!(
+                "Missing permission: {}",
+                self.permission
+            ))))
+        }
+    }
+}
+
+/// Passes only for users who do not belong to an organization yet.
+pub struct NoOrganizationGuard;
+
+impl Guard for NoOrganizationGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let user = current_user(ctx).await?;
+
+        if user.organization_id == NO_ORGANIZATION.to_string() {
+            Ok(())
+        } else {
+            Err(graphql_error(AppError::Forbidden(
+                "Caller already belongs to an organization".into(),
+            )))
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use async_graphql::Context;
+use shared::utils::locale_utils::{Lang, Messages};
+
+/// Messages in the request's `Accept-Language`, which the HTTP handler stores as a `Lang`.
+pub fn current_messages(ctx: &Context<'_>) -> Messages {
+    Messages::new(ctx.data_opt::<Lang>().copied().unwrap_or(Lang::En))
+}
@@ -1 +1,3 @@
 pub mod auth;
+pub mod guard;
+pub mod locale;
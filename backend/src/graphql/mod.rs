@@ -2,4 +2,5 @@ pub mod context;
 pub mod error;
 pub mod middleware;
 pub mod modules;
+pub mod pagination;
 pub mod schema;
@@ -0,0 +1,150 @@
+use async_graphql::{ID, InputObject};
+use chrono::{DateTime, NaiveDate, Utc};
+use shared::types::{
+    models::{
+        attendance::{
+            attendance_method::AttendanceMethod, attendance_sort_field::AttendanceSortField,
+            attendance_status::AttendanceStatus, attendance_type::AttendanceType,
+            report_granularity::ReportGranularity,
+        },
+        sort_direction::SortDirection,
+    },
+    requests::attendance::{
+        attendance_summary_request::AttendanceSummaryRequest, clock_request::ClockRequest,
+        list_attendances_request::ListAttendancesRequest, qr_check_in_request::QrCheckInRequest,
+        register_attendance_request::RegisterAttendanceRequest,
+    },
+};
+
+#[derive(InputObject)]
+pub struct RegisterAttendanceInput {
+    pub user_id: ID,
+    pub organization_id: ID,
+    pub date: DateTime<Utc>,
+    pub clock_in: Option<DateTime<Utc>>,
+    pub clock_out: Option<DateTime<Utc>>,
+    pub method: Option<AttendanceMethod>,
+    pub status: Option<AttendanceStatus>,
+    pub attendance_type: Option<AttendanceType>,
+    pub lat: Option<f64>,
+    pub long: Option<f64>,
+}
+
+impl RegisterAttendanceInput {
+    pub fn to_register_attendance_request(self) -> RegisterAttendanceRequest {
+        RegisterAttendanceRequest {
+            user_id: self.user_id.to_string(),
+            organization_id: self.organization_id.to_string(),
+            date: self.date,
+            clock_in: self.clock_in,
+            clock_out: self.clock_out,
+            method: self.method,
+            status: self.status,
+            attendance_type: self.attendance_type,
+            lat: self.lat,
+            long: self.long,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct GeoLocationInput {
+    pub lat: f64,
+    pub long: f64,
+}
+
+#[derive(InputObject, Default)]
+pub struct ClockInput {
+    pub method: Option<AttendanceMethod>,
+    pub lat: Option<f64>,
+    pub long: Option<f64>,
+}
+
+impl ClockInput {
+    pub fn to_clock_request(self) -> ClockRequest {
+        ClockRequest {
+            method: self.method,
+            lat: self.lat,
+            long: self.long,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct QrCheckInInput {
+    pub code: String,
+    pub lat: Option<f64>,
+    pub long: Option<f64>,
+}
+
+impl QrCheckInInput {
+    pub fn to_qr_check_in_request(self) -> QrCheckInRequest {
+        QrCheckInRequest {
+            code: self.code,
+            lat: self.lat,
+            long: self.long,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AttendanceSummaryInput {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    #[graphql(default)]
+    pub group_by: ReportGranularity,
+}
+
+impl AttendanceSummaryInput {
+    pub fn to_attendance_summary_request(self) -> AttendanceSummaryRequest {
+        AttendanceSummaryRequest {
+            from: self.from,
+            to: self.to,
+            group_by: self.group_by,
+        }
+    }
+}
+
+/// `from` and `to` are organization-local days, both inclusive.
+#[derive(InputObject, Default)]
+pub struct AttendanceFilterInput {
+    pub organization_id: Option<ID>,
+    pub user_id: Option<ID>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub status: Option<AttendanceStatus>,
+    pub method: Option<AttendanceMethod>,
+}
+
+/// Newest days first unless asked otherwise.
+#[derive(InputObject)]
+pub struct AttendanceSortInput {
+    #[graphql(default)]
+    pub field: AttendanceSortField,
+    #[graphql(default_with = "SortDirection::Desc")]
+    pub direction: SortDirection,
+}
+
+impl Default for AttendanceSortInput {
+    fn default() -> Self {
+        Self {
+            field: AttendanceSortField::default(),
+            direction: SortDirection::Desc,
+        }
+    }
+}
+
+impl AttendanceFilterInput {
+    pub fn to_list_attendances_request(self, sort: AttendanceSortInput) -> ListAttendancesRequest {
+        ListAttendancesRequest {
+            organization_id: self.organization_id.map(|id| id.to_string()),
+            user_id: self.user_id.map(|id| id.to_string()),
+            from: self.from,
+            to: self.to,
+            status: self.status,
+            method: self.method,
+            sort_by: sort.field,
+            direction: sort.direction,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod input;
+pub mod model;
+pub mod mutation;
+pub mod query;
+pub mod subscription;
+
+pub use mutation::AttendanceMutation;
+pub use query::AttendanceQuery;
+pub use subscription::AttendanceSubscription;
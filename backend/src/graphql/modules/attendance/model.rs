@@ -0,0 +1,122 @@
+use crate::services::attendance_service::{AttendanceEvent, AttendanceEventKind};
+use async_graphql::{ID, SimpleObject};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::{
+    models::attendance::{
+        attendance_method::AttendanceMethod, attendance_status::AttendanceStatus,
+        attendance_type::AttendanceType,
+    },
+    responses::{
+        attendance_response::AttendanceResponse,
+        attendance_summary_response::AttendanceSummaryResponse, qr_code_response::QrCodeResponse,
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct AttendanceObject {
+    pub id: ID,
+    pub user_id: ID,
+    pub organization_id: ID,
+    pub date: DateTime<Utc>,
+    pub local_date: NaiveDate,
+    pub clock_in: Option<DateTime<Utc>>,
+    pub clock_out: Option<DateTime<Utc>>,
+    pub worked_minutes: Option<i64>,
+    pub method: AttendanceMethod,
+    pub status: AttendanceStatus,
+    pub attendance_type: AttendanceType,
+    pub lat: Option<f64>,
+    pub long: Option<f64>,
+    pub distance_meters: Option<f64>,
+    pub outside_geofence: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AttendanceResponse> for AttendanceObject {
+    fn from(a: AttendanceResponse) -> Self {
+        Self {
+            id: a.id.into(),
+            user_id: a.user_id.into(),
+            organization_id: a.organization_id.into(),
+            date: a.date,
+            local_date: a.local_date,
+            clock_in: a.clock_in,
+            clock_out: a.clock_out,
+            worked_minutes: a.worked_minutes,
+            method: a.method,
+            status: a.status,
+            attendance_type: a.attendance_type,
+            lat: a.lat,
+            long: a.long,
+            distance_meters: a.distance_meters,
+            outside_geofence: a.outside_geofence,
+            created_at: a.created_at,
+            updated_at: a.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct AttendanceEventObject {
+    pub kind: AttendanceEventKind,
+    pub attendance: AttendanceObject,
+}
+
+impl From<AttendanceEvent> for AttendanceEventObject {
+    fn from(event: AttendanceEvent) -> Self {
+        Self {
+            kind: event.kind,
+            attendance: AttendanceObject::from(event.attendance),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct QrCodeObject {
+    pub code: String,
+    pub expires_at: DateTime<Utc>,
+    pub rotation_seconds: i64,
+}
+
+impl From<QrCodeResponse> for QrCodeObject {
+    fn from(q: QrCodeResponse) -> Self {
+        Self {
+            code: q.code,
+            expires_at: q.expires_at,
+            rotation_seconds: q.rotation_seconds,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct AttendanceSummaryObject {
+    pub period_start: NaiveDate,
+    pub user_id: Option<ID>,
+    pub present: i64,
+    pub late: i64,
+    pub absent: i64,
+    pub excused: i64,
+    /// Share of non-excused days that were present or late; null when there were none.
+    pub attendance_rate: Option<f64>,
+    /// Average clock-in wall time in the organization's timezone.
+    pub average_clock_in: Option<NaiveTime>,
+    pub total_hours: f64,
+}
+
+impl From<AttendanceSummaryResponse> for AttendanceSummaryObject {
+    fn from(s: AttendanceSummaryResponse) -> Self {
+        Self {
+            period_start: s.period_start,
+            user_id: s.user_id.map(ID::from),
+            present: s.present,
+            late: s.late,
+            absent: s.absent,
+            excused: s.excused,
+            attendance_rate: s.attendance_rate,
+            average_clock_in: s.average_clock_in,
+            total_hours: s.total_hours,
+        }
+    }
+}
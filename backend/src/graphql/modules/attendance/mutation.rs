@@ -0,0 +1,96 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::attendance::{
+    input::{ClockInput, QrCheckInInput, RegisterAttendanceInput},
+    model::AttendanceObject,
+};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct AttendanceMutation;
+
+#[Object]
+impl AttendanceMutation {
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn clock_in(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] input: ClockInput,
+    ) -> Result<AttendanceObject> {
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let attendance = svc
+            .clock_in(&user.id, &user.organization_id, input.to_clock_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(AttendanceObject::from(attendance))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn check_in_with_qr(
+        &self,
+        ctx: &Context<'_>,
+        input: QrCheckInInput,
+    ) -> Result<AttendanceObject> {
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let attendance = svc
+            .check_in_with_qr(
+                &user.id,
+                &user.organization_id,
+                input.to_qr_check_in_request(),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(AttendanceObject::from(attendance))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn clock_out(&self, ctx: &Context<'_>) -> Result<AttendanceObject> {
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let attendance = svc
+            .clock_out(&user.id, &user.organization_id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(AttendanceObject::from(attendance))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn create_attendance(
+        &self,
+        ctx: &Context<'_>,
+        input: RegisterAttendanceInput,
+    ) -> Result<AttendanceObject> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let created = svc
+            .create_attendance(scope, input.to_register_attendance_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(AttendanceObject::from(created))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn delete_attendance(&self, ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        svc.delete_attendance(scope, &id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,207 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::attendance::input::{
+    AttendanceFilterInput, AttendanceSortInput, AttendanceSummaryInput,
+};
+use crate::graphql::modules::attendance::model::{
+    AttendanceObject, AttendanceSummaryObject, QrCodeObject,
+};
+use crate::graphql::pagination::{PageConnection, paginate};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct AttendanceQuery;
+
+#[Object]
+impl AttendanceQuery {
+    /// Members may only read their own records.
+    #[graphql(guard = "PermissionGuard::new(Permission::ViewAttendance)")]
+    async fn attendance(&self, ctx: &Context<'_>, id: ID) -> Result<Option<AttendanceObject>> {
+        let scope = current_scope(ctx).await?;
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let attendance = svc
+            .get_attendance_by_id(
+                scope,
+                &id,
+                &user.id,
+                user.role.has_permission(Permission::ManageAttendance),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(attendance.map(AttendanceObject::from))
+    }
+
+    /// Members only ever get their own records.
+    #[graphql(guard = "PermissionGuard::new(Permission::ViewAttendance)")]
+    #[allow(clippy::too_many_arguments)]
+    async fn attendances(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] filter: AttendanceFilterInput,
+        #[graphql(default)] sort: AttendanceSortInput,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<PageConnection<AttendanceObject>> {
+        let scope = current_scope(ctx).await?;
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+        let request = filter.to_list_attendances_request(sort);
+
+        paginate(ctx, after, before, first, last, |page| async move {
+            let attendances = svc
+                .list_attendances(
+                    scope,
+                    &user.id,
+                    user.role.has_permission(Permission::ManageAttendance),
+                    request,
+                    page,
+                )
+                .await
+                .map_err(|e| graphql_error(e.into()))?;
+
+            Ok(attendances.map(AttendanceObject::from))
+        })
+        .await
+    }
+
+    #[graphql(
+        guard = "PermissionGuard::new(Permission::ManageAttendance)",
+        deprecation = "Use `attendances` with an `organizationId` filter."
+    )]
+    async fn attendances_for_org(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: ID,
+    ) -> Result<Vec<AttendanceObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let attendances = svc
+            .get_attendances_for_org(scope, &organization_id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(attendances
+            .into_iter()
+            .map(AttendanceObject::from)
+            .collect())
+    }
+
+    /// Members may only pass their own `userId`.
+    #[graphql(
+        guard = "PermissionGuard::new(Permission::ViewAttendance)",
+        deprecation = "Use `attendances` with a `userId` filter."
+    )]
+    async fn attendances_for_user(
+        &self,
+        ctx: &Context<'_>,
+        user_id: ID,
+        organization_id: ID,
+    ) -> Result<Vec<AttendanceObject>> {
+        let scope = current_scope(ctx).await?;
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let attendances = svc
+            .get_attendances_for_user_in_org(
+                scope,
+                &user_id,
+                &organization_id,
+                &user.id,
+                user.role.has_permission(Permission::ManageAttendance),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(attendances
+            .into_iter()
+            .map(AttendanceObject::from)
+            .collect())
+    }
+
+    /// Current rotating check-in code for a room or site display.
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn qr_code(&self, ctx: &Context<'_>, geofence_id: Option<ID>) -> Result<QrCodeObject> {
+        let scope = current_scope(ctx).await?;
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let qr_code = svc
+            .issue_qr_code(
+                scope,
+                &user.organization_id,
+                geofence_id.as_deref().map(String::as_str),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(QrCodeObject::from(qr_code))
+    }
+
+    /// Organization-wide counts per day, week or month; defaults to the caller's organization.
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn attendance_summary(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: Option<ID>,
+        input: AttendanceSummaryInput,
+    ) -> Result<Vec<AttendanceSummaryObject>> {
+        let scope = current_scope(ctx).await?;
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let summary = svc
+            .get_organization_summary(
+                scope,
+                organization_id
+                    .as_deref()
+                    .map_or(user.organization_id.as_str(), String::as_str),
+                input.to_attendance_summary_request(),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(summary
+            .into_iter()
+            .map(AttendanceSummaryObject::from)
+            .collect())
+    }
+
+    /// Per-user counts; members only ever get their own rows.
+    #[graphql(guard = "PermissionGuard::new(Permission::ViewAttendance)")]
+    async fn user_attendance_summary(
+        &self,
+        ctx: &Context<'_>,
+        user_id: Option<ID>,
+        input: AttendanceSummaryInput,
+    ) -> Result<Vec<AttendanceSummaryObject>> {
+        let scope = current_scope(ctx).await?;
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let summary = svc
+            .get_user_summaries(
+                scope,
+                &user.organization_id,
+                &user.id,
+                user.role.has_permission(Permission::ManageAttendance),
+                user_id.as_deref().map(String::as_str),
+                input.to_attendance_summary_request(),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(summary
+            .into_iter()
+            .map(AttendanceSummaryObject::from)
+            .collect())
+    }
+}
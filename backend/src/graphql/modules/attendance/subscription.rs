@@ -0,0 +1,51 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::attendance::model::AttendanceEventObject;
+use async_graphql::{Context, ID, Result, Subscription};
+use futures::{Stream, StreamExt, stream};
+use shared::types::models::user::permissions::Permission;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct AttendanceSubscription;
+
+#[Subscription]
+impl AttendanceSubscription {
+    /// Callers without `ManageAttendance` only receive events for their own records.
+    #[graphql(guard = "PermissionGuard::new(Permission::ViewAttendance)")]
+    async fn attendance_events(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: Option<ID>,
+    ) -> Result<impl Stream<Item = AttendanceEventObject>> {
+        let scope = current_scope(ctx).await?;
+        let user = current_user(ctx).await?;
+        let own_only = !user.role.has_permission(Permission::ManageAttendance);
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let events = stream::unfold(svc.subscribe(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => return Some((event, rx)),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        });
+
+        Ok(events
+            .filter(move |event| {
+                let visible = Uuid::parse_str(&event.attendance.organization_id)
+                    .is_ok_and(|org_id| scope.allows(org_id));
+                let matches = visible
+                    && (!own_only || event.attendance.user_id == user.id)
+                    && organization_id
+                        .as_ref()
+                        .is_none_or(|org_id| event.attendance.organization_id == org_id.as_str());
+                async move { matches }
+            })
+            .map(AttendanceEventObject::from))
+    }
+}
@@ -0,0 +1,39 @@
+use async_graphql::InputObject;
+use shared::types::requests::auth::{
+    login_request::LoginRequest, register_request::RegisterRequest,
+};
+
+#[derive(InputObject)]
+pub struct RegisterInput {
+    pub name: String,
+    pub email: String,
+    #[graphql(secret)]
+    pub password: String,
+}
+
+impl RegisterInput {
+    pub fn to_register_request(self) -> RegisterRequest {
+        RegisterRequest {
+            id: None,
+            name: self.name,
+            email: self.email,
+            password: Some(self.password),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct LoginInput {
+    pub email: String,
+    #[graphql(secret)]
+    pub password: String,
+}
+
+impl LoginInput {
+    pub fn to_login_request(self) -> LoginRequest {
+        LoginRequest {
+            email: self.email,
+            password: self.password,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod input;
+pub mod model;
+pub mod mutation;
+
+pub use mutation::AuthMutation;
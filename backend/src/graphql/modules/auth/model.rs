@@ -0,0 +1,21 @@
+use crate::graphql::modules::user::model::UserObject;
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+use shared::types::responses::auth_response::AuthResponse;
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct AuthObject {
+    pub token: String,
+    pub refresh_token: String,
+    pub user: UserObject,
+}
+
+impl From<AuthResponse> for AuthObject {
+    fn from(a: AuthResponse) -> Self {
+        Self {
+            token: a.token,
+            refresh_token: a.refresh_token,
+            user: UserObject::from(a.user),
+        }
+    }
+}
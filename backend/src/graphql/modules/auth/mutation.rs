@@ -0,0 +1,106 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::AppError;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{extract_user_id, refresh_token_from};
+use crate::graphql::middleware::locale::current_messages;
+use crate::graphql::modules::auth::{
+    input::{LoginInput, RegisterInput},
+    model::AuthObject,
+};
+use crate::utils::auth_utils::{
+    clear_cookie, clear_refresh_cookie, generate_cookie, generate_refresh_cookie,
+};
+use async_graphql::{Context, Object, Result};
+use shared::types::responses::auth_response::AuthResponse;
+
+/// Both tokens go out as httpOnly cookies so browsers can renew sessions without script access.
+fn set_session_cookies(ctx: &Context<'_>, session: &AuthResponse) {
+    ctx.append_http_header(
+        "Set-Cookie",
+        generate_cookie(session.token.clone()).to_string(),
+    );
+    ctx.append_http_header(
+        "Set-Cookie",
+        generate_refresh_cookie(session.refresh_token.clone()).to_string(),
+    );
+}
+
+fn clear_session_cookies(ctx: &Context<'_>) {
+    ctx.append_http_header("Set-Cookie", clear_cookie().to_string());
+    ctx.append_http_header("Set-Cookie", clear_refresh_cookie().to_string());
+}
+
+#[derive(Default)]
+pub struct AuthMutation;
+
+#[Object]
+impl AuthMutation {
+    async fn register(&self, ctx: &Context<'_>, input: RegisterInput) -> Result<AuthObject> {
+        let svc = &ctx.data::<GQLContext>()?.app_service.auth_service;
+
+        let registered = svc
+            .register(input.to_register_request(), &current_messages(ctx))
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        set_session_cookies(ctx, &registered);
+        Ok(AuthObject::from(registered))
+    }
+
+    async fn login(&self, ctx: &Context<'_>, input: LoginInput) -> Result<AuthObject> {
+        let svc = &ctx.data::<GQLContext>()?.app_service.auth_service;
+
+        let logged_in = svc
+            .login(input.to_login_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        set_session_cookies(ctx, &logged_in);
+        Ok(AuthObject::from(logged_in))
+    }
+
+    /// Browsers can omit `refreshToken`; the one in the refresh cookie is used instead.
+    async fn refresh_token(
+        &self,
+        ctx: &Context<'_>,
+        refresh_token: Option<String>,
+    ) -> Result<AuthObject> {
+        let refresh_token = refresh_token_from(ctx, refresh_token)
+            .ok_or_else(|| graphql_error(AppError::Unauthorized))?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.auth_service;
+
+        let refreshed = svc
+            .refresh(&refresh_token)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        set_session_cookies(ctx, &refreshed);
+        Ok(AuthObject::from(refreshed))
+    }
+
+    async fn logout(&self, ctx: &Context<'_>, refresh_token: Option<String>) -> Result<bool> {
+        let svc = &ctx.data::<GQLContext>()?.app_service.auth_service;
+
+        if let Some(refresh_token) = refresh_token_from(ctx, refresh_token) {
+            svc.logout(&refresh_token)
+                .await
+                .map_err(|e| graphql_error(e.into()))?;
+        }
+
+        clear_session_cookies(ctx);
+        Ok(true)
+    }
+
+    async fn logout_all_sessions(&self, ctx: &Context<'_>) -> Result<i32> {
+        let user_id = extract_user_id(ctx)?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.auth_service;
+
+        let revoked = svc
+            .logout_all_sessions(&user_id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        clear_session_cookies(ctx);
+        Ok(revoked as i32)
+    }
+}
@@ -0,0 +1,28 @@
+use async_graphql::{ID, InputObject};
+use chrono::{DateTime, Utc};
+use shared::types::{
+    models::attendance::attendance_status::AttendanceStatus,
+    requests::attendance::submit_correction_request::SubmitCorrectionRequest,
+};
+
+/// Omitted fields keep their current value.
+#[derive(InputObject)]
+pub struct SubmitCorrectionInput {
+    pub attendance_id: ID,
+    pub clock_in: Option<DateTime<Utc>>,
+    pub clock_out: Option<DateTime<Utc>>,
+    pub status: Option<AttendanceStatus>,
+    pub reason: String,
+}
+
+impl SubmitCorrectionInput {
+    pub fn to_submit_correction_request(self) -> SubmitCorrectionRequest {
+        SubmitCorrectionRequest {
+            attendance_id: self.attendance_id.to_string(),
+            clock_in: self.clock_in,
+            clock_out: self.clock_out,
+            status: self.status,
+            reason: self.reason,
+        }
+    }
+}
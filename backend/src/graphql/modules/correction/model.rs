@@ -0,0 +1,54 @@
+use async_graphql::{ID, SimpleObject};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::{
+    models::attendance::{
+        attendance_status::AttendanceStatus, correction_status::CorrectionStatus,
+    },
+    responses::attendance_correction_response::AttendanceCorrectionResponse,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct AttendanceCorrectionObject {
+    pub id: ID,
+    pub organization_id: ID,
+    pub attendance_id: ID,
+    pub requested_by: ID,
+    pub original_clock_in: Option<DateTime<Utc>>,
+    pub original_clock_out: Option<DateTime<Utc>>,
+    pub original_status: AttendanceStatus,
+    pub proposed_clock_in: Option<DateTime<Utc>>,
+    pub proposed_clock_out: Option<DateTime<Utc>>,
+    pub proposed_status: Option<AttendanceStatus>,
+    pub reason: String,
+    pub status: CorrectionStatus,
+    pub reviewed_by: Option<ID>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AttendanceCorrectionResponse> for AttendanceCorrectionObject {
+    fn from(c: AttendanceCorrectionResponse) -> Self {
+        Self {
+            id: c.id.into(),
+            organization_id: c.organization_id.into(),
+            attendance_id: c.attendance_id.into(),
+            requested_by: c.requested_by.into(),
+            original_clock_in: c.original_clock_in,
+            original_clock_out: c.original_clock_out,
+            original_status: c.original_status,
+            proposed_clock_in: c.proposed_clock_in,
+            proposed_clock_out: c.proposed_clock_out,
+            proposed_status: c.proposed_status,
+            reason: c.reason,
+            status: c.status,
+            reviewed_by: c.reviewed_by.map(ID::from),
+            reviewed_at: c.reviewed_at,
+            review_note: c.review_note,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_user, tenant_scope};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::correction::{
+    input::SubmitCorrectionInput, model::AttendanceCorrectionObject,
+};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::{
+    models::user::permissions::Permission,
+    requests::attendance::review_correction_request::ReviewCorrectionRequest,
+};
+
+#[derive(Default)]
+pub struct CorrectionMutation;
+
+#[Object]
+impl CorrectionMutation {
+    /// Members may correct their own attendance; managers may file for anyone in scope.
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn request_attendance_correction(
+        &self,
+        ctx: &Context<'_>,
+        input: SubmitCorrectionInput,
+    ) -> Result<AttendanceCorrectionObject> {
+        let caller = current_user(ctx).await?;
+        let scope = tenant_scope(&caller).map_err(graphql_error)?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.correction_service;
+
+        let correction = svc
+            .submit_correction(
+                scope,
+                &caller.id,
+                caller.role.has_permission(Permission::ManageAttendance),
+                input.to_submit_correction_request(),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(AttendanceCorrectionObject::from(correction))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn cancel_attendance_correction(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> Result<AttendanceCorrectionObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.correction_service;
+
+        let correction = svc
+            .cancel_correction(&caller.id, &id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(AttendanceCorrectionObject::from(correction))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn approve_attendance_correction(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        note: Option<String>,
+    ) -> Result<AttendanceCorrectionObject> {
+        review(ctx, id, true, note).await
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn reject_attendance_correction(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        note: Option<String>,
+    ) -> Result<AttendanceCorrectionObject> {
+        review(ctx, id, false, note).await
+    }
+}
+
+async fn review(
+    ctx: &Context<'_>,
+    id: ID,
+    approve: bool,
+    note: Option<String>,
+) -> Result<AttendanceCorrectionObject> {
+    let caller = current_user(ctx).await?;
+    let scope = tenant_scope(&caller).map_err(graphql_error)?;
+    let svc = &ctx.data::<GQLContext>()?.app_service.correction_service;
+
+    let correction = svc
+        .review_correction(
+            scope,
+            &caller.id,
+            &id,
+            ReviewCorrectionRequest { approve, note },
+        )
+        .await
+        .map_err(|e| graphql_error(e.into()))?;
+
+    Ok(AttendanceCorrectionObject::from(correction))
+}
@@ -0,0 +1,57 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_user, tenant_scope};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::correction::model::AttendanceCorrectionObject;
+use async_graphql::{Context, Object, Result};
+use shared::types::models::{
+    attendance::correction_status::CorrectionStatus, user::permissions::Permission,
+};
+
+#[derive(Default)]
+pub struct CorrectionQuery;
+
+#[Object]
+impl CorrectionQuery {
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn my_attendance_corrections(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<CorrectionStatus>,
+    ) -> Result<Vec<AttendanceCorrectionObject>> {
+        let caller = current_user(ctx).await?;
+        let scope = tenant_scope(&caller).map_err(graphql_error)?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.correction_service;
+
+        let corrections = svc
+            .get_corrections(scope, Some(&caller.id), status)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(corrections
+            .into_iter()
+            .map(AttendanceCorrectionObject::from)
+            .collect())
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn attendance_corrections(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<CorrectionStatus>,
+    ) -> Result<Vec<AttendanceCorrectionObject>> {
+        let caller = current_user(ctx).await?;
+        let scope = tenant_scope(&caller).map_err(graphql_error)?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.correction_service;
+
+        let corrections = svc
+            .get_corrections(scope, None, status)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(corrections
+            .into_iter()
+            .map(AttendanceCorrectionObject::from)
+            .collect())
+    }
+}
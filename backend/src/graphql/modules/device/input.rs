@@ -0,0 +1,37 @@
+use async_graphql::{ID, InputObject};
+use shared::types::{
+    models::attendance::device_kind::DeviceKind,
+    requests::device::{
+        assign_badge_request::AssignBadgeRequest, register_device_request::RegisterDeviceRequest,
+    },
+};
+
+#[derive(InputObject)]
+pub struct RegisterDeviceInput {
+    pub name: String,
+    pub kind: DeviceKind,
+}
+
+impl RegisterDeviceInput {
+    pub fn to_register_device_request(self) -> RegisterDeviceRequest {
+        RegisterDeviceRequest {
+            name: self.name,
+            kind: self.kind,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AssignBadgeInput {
+    pub user_id: ID,
+    pub uid: String,
+}
+
+impl AssignBadgeInput {
+    pub fn to_assign_badge_request(self) -> AssignBadgeRequest {
+        AssignBadgeRequest {
+            user_id: self.user_id.to_string(),
+            uid: self.uid,
+        }
+    }
+}
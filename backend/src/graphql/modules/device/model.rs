@@ -0,0 +1,73 @@
+use async_graphql::{ID, SimpleObject};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::{
+    models::attendance::device_kind::DeviceKind,
+    responses::{
+        badge_response::BadgeResponse,
+        device_response::{DeviceResponse, RegisteredDeviceResponse},
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct DeviceObject {
+    pub id: ID,
+    pub organization_id: ID,
+    pub name: String,
+    pub kind: DeviceKind,
+    pub last_seen_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DeviceResponse> for DeviceObject {
+    fn from(d: DeviceResponse) -> Self {
+        Self {
+            id: d.id.into(),
+            organization_id: d.organization_id.into(),
+            name: d.name,
+            kind: d.kind,
+            last_seen_at: d.last_seen_at,
+            revoked_at: d.revoked_at,
+            created_at: d.created_at,
+            updated_at: d.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct RegisteredDeviceObject {
+    pub device: DeviceObject,
+    pub api_key: String,
+}
+
+impl From<RegisteredDeviceResponse> for RegisteredDeviceObject {
+    fn from(r: RegisteredDeviceResponse) -> Self {
+        Self {
+            device: DeviceObject::from(r.device),
+            api_key: r.api_key,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct BadgeObject {
+    pub id: ID,
+    pub organization_id: ID,
+    pub user_id: ID,
+    pub uid: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<BadgeResponse> for BadgeObject {
+    fn from(b: BadgeResponse) -> Self {
+        Self {
+            id: b.id.into(),
+            organization_id: b.organization_id.into(),
+            user_id: b.user_id.into(),
+            uid: b.uid,
+            created_at: b.created_at,
+        }
+    }
+}
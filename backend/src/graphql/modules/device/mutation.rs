@@ -0,0 +1,75 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::device::{
+    input::{AssignBadgeInput, RegisterDeviceInput},
+    model::{BadgeObject, RegisteredDeviceObject},
+};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct DeviceMutation;
+
+#[Object]
+impl DeviceMutation {
+    /// The returned API key is shown only once.
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn register_device(
+        &self,
+        ctx: &Context<'_>,
+        input: RegisterDeviceInput,
+    ) -> Result<RegisteredDeviceObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.device_service;
+
+        let registered = svc
+            .register_device(&caller.organization_id, input.to_register_device_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(RegisteredDeviceObject::from(registered))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn revoke_device(&self, ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.device_service;
+
+        svc.revoke_device(scope, &id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn assign_badge(
+        &self,
+        ctx: &Context<'_>,
+        input: AssignBadgeInput,
+    ) -> Result<BadgeObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.device_service;
+
+        let badge = svc
+            .assign_badge(&caller.organization_id, input.to_assign_badge_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(BadgeObject::from(badge))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn remove_badge(&self, ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.device_service;
+
+        svc.remove_badge(scope, &id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::current_scope;
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::device::model::{BadgeObject, DeviceObject};
+use async_graphql::{Context, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct DeviceQuery;
+
+#[Object]
+impl DeviceQuery {
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn devices(&self, ctx: &Context<'_>) -> Result<Vec<DeviceObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.device_service;
+
+        let devices = svc
+            .get_devices(scope)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(devices.into_iter().map(DeviceObject::from).collect())
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn badges(&self, ctx: &Context<'_>) -> Result<Vec<BadgeObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.device_service;
+
+        let badges = svc
+            .get_badges(scope)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(badges.into_iter().map(BadgeObject::from).collect())
+    }
+}
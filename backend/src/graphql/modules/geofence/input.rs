@@ -0,0 +1,34 @@
+use crate::graphql::modules::attendance::input::GeoLocationInput;
+use async_graphql::InputObject;
+use shared::types::{
+    models::attendance::geolocation::GeoLocation,
+    requests::geofence::geofence_request::GeofenceRequest,
+};
+
+#[derive(InputObject)]
+pub struct GeofenceInput {
+    pub name: String,
+    pub center: Option<GeoLocationInput>,
+    pub radius_meters: Option<f64>,
+    pub polygon: Option<Vec<GeoLocationInput>>,
+}
+
+impl GeofenceInput {
+    pub fn to_geofence_request(self) -> GeofenceRequest {
+        GeofenceRequest {
+            name: self.name,
+            center: self.center.map(to_geolocation),
+            radius_meters: self.radius_meters,
+            polygon: self
+                .polygon
+                .map(|points| points.into_iter().map(to_geolocation).collect()),
+        }
+    }
+}
+
+fn to_geolocation(input: GeoLocationInput) -> GeoLocation {
+    GeoLocation {
+        lat: input.lat,
+        long: input.long,
+    }
+}
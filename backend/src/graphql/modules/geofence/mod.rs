@@ -0,0 +1,7 @@
+pub mod input;
+pub mod model;
+pub mod mutation;
+pub mod query;
+
+pub use mutation::GeofenceMutation;
+pub use query::GeofenceQuery;
@@ -0,0 +1,50 @@
+use async_graphql::{ID, SimpleObject};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::{
+    models::attendance::geolocation::GeoLocation, responses::geofence_response::GeofenceResponse,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct GeoLocationObject {
+    pub lat: f64,
+    pub long: f64,
+}
+
+impl From<GeoLocation> for GeoLocationObject {
+    fn from(location: GeoLocation) -> Self {
+        Self {
+            lat: location.lat,
+            long: location.long,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct GeofenceObject {
+    pub id: ID,
+    pub organization_id: ID,
+    pub name: String,
+    pub center: Option<GeoLocationObject>,
+    pub radius_meters: Option<f64>,
+    pub polygon: Option<Vec<GeoLocationObject>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<GeofenceResponse> for GeofenceObject {
+    fn from(g: GeofenceResponse) -> Self {
+        Self {
+            id: g.id.into(),
+            organization_id: g.organization_id.into(),
+            name: g.name,
+            center: g.center.map(GeoLocationObject::from),
+            radius_meters: g.radius_meters,
+            polygon: g
+                .polygon
+                .map(|points| points.into_iter().map(GeoLocationObject::from).collect()),
+            created_at: g.created_at,
+            updated_at: g.updated_at,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::geofence::{input::GeofenceInput, model::GeofenceObject};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct GeofenceMutation;
+
+#[Object]
+impl GeofenceMutation {
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn create_geofence(
+        &self,
+        ctx: &Context<'_>,
+        input: GeofenceInput,
+    ) -> Result<GeofenceObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.geofence_service;
+
+        let created = svc
+            .create_geofence(&caller.organization_id, input.to_geofence_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(GeofenceObject::from(created))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn update_geofence(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        input: GeofenceInput,
+    ) -> Result<GeofenceObject> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.geofence_service;
+
+        let updated = svc
+            .update_geofence(scope, &id, input.to_geofence_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(GeofenceObject::from(updated))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn delete_geofence(&self, ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.geofence_service;
+
+        svc.delete_geofence(scope, &id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+}
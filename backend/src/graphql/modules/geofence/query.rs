@@ -0,0 +1,26 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::current_scope;
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::geofence::model::GeofenceObject;
+use async_graphql::{Context, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct GeofenceQuery;
+
+#[Object]
+impl GeofenceQuery {
+    #[graphql(guard = "PermissionGuard::new(Permission::ViewAttendance)")]
+    async fn geofences(&self, ctx: &Context<'_>) -> Result<Vec<GeofenceObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.geofence_service;
+
+        let geofences = svc
+            .get_geofences(scope)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(geofences.into_iter().map(GeofenceObject::from).collect())
+    }
+}
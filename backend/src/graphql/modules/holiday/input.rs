@@ -0,0 +1,21 @@
+use async_graphql::InputObject;
+use chrono::NaiveDate;
+use shared::types::requests::schedule::holiday_request::HolidayRequest;
+
+#[derive(InputObject)]
+pub struct HolidayInput {
+    pub name: String,
+    pub date: NaiveDate,
+    #[graphql(default)]
+    pub recurring: bool,
+}
+
+impl HolidayInput {
+    pub fn to_holiday_request(self) -> HolidayRequest {
+        HolidayRequest {
+            name: self.name,
+            date: self.date,
+            recurring: self.recurring,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use async_graphql::{ID, SimpleObject};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::responses::{
+    holiday_import_response::{HolidayImportResponse, SkippedHolidayEvent},
+    holiday_response::HolidayResponse,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct HolidayObject {
+    pub id: ID,
+    pub organization_id: ID,
+    pub name: String,
+    pub date: NaiveDate,
+    pub recurring: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<HolidayResponse> for HolidayObject {
+    fn from(h: HolidayResponse) -> Self {
+        Self {
+            id: h.id.into(),
+            organization_id: h.organization_id.into(),
+            name: h.name,
+            date: h.date,
+            recurring: h.recurring,
+            created_at: h.created_at,
+            updated_at: h.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SkippedHolidayEventObject {
+    pub uid: Option<String>,
+    pub name: String,
+    pub rrule: String,
+    pub reason: String,
+}
+
+impl From<SkippedHolidayEvent> for SkippedHolidayEventObject {
+    fn from(e: SkippedHolidayEvent) -> Self {
+        Self {
+            uid: e.uid,
+            name: e.name,
+            rrule: e.rrule,
+            reason: e.reason,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct HolidayImportReportObject {
+    pub holidays: Vec<HolidayObject>,
+    /// Events whose recurrence cannot be stored as a holiday.
+    pub skipped: Vec<SkippedHolidayEventObject>,
+}
+
+impl From<HolidayImportResponse> for HolidayImportReportObject {
+    fn from(r: HolidayImportResponse) -> Self {
+        Self {
+            holidays: r.holidays.into_iter().map(HolidayObject::from).collect(),
+            skipped: r
+                .skipped
+                .into_iter()
+                .map(SkippedHolidayEventObject::from)
+                .collect(),
+        }
+    }
+}
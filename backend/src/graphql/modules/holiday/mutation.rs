@@ -0,0 +1,64 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::middleware::locale::current_messages;
+use crate::graphql::modules::holiday::{
+    input::HolidayInput,
+    model::{HolidayImportReportObject, HolidayObject},
+};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct HolidayMutation;
+
+#[Object]
+impl HolidayMutation {
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn create_holiday(
+        &self,
+        ctx: &Context<'_>,
+        input: HolidayInput,
+    ) -> Result<HolidayObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.holiday_service;
+
+        let holiday = svc
+            .create_holiday(&caller.organization_id, input.to_holiday_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(HolidayObject::from(holiday))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn delete_holiday(&self, ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.holiday_service;
+
+        svc.delete_holiday(scope, &id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+
+    /// Takes the contents of an iCalendar (.ics) file.
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn import_holidays(
+        &self,
+        ctx: &Context<'_>,
+        ics: String,
+    ) -> Result<HolidayImportReportObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.holiday_service;
+
+        let report = svc
+            .import_holidays(&caller.organization_id, &ics, &current_messages(ctx))
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(HolidayImportReportObject::from(report))
+    }
+}
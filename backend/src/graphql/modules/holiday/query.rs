@@ -0,0 +1,33 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::current_scope;
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::holiday::model::HolidayObject;
+use async_graphql::{Context, Object, Result};
+use chrono::NaiveDate;
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct HolidayQuery;
+
+#[Object]
+impl HolidayQuery {
+    /// Recurring holidays are returned once, with the date they were first observed.
+    #[graphql(guard = "PermissionGuard::new(Permission::ViewAttendance)")]
+    async fn holidays(
+        &self,
+        ctx: &Context<'_>,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<HolidayObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.holiday_service;
+
+        let holidays = svc
+            .get_holidays(scope, from, to)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(holidays.into_iter().map(HolidayObject::from).collect())
+    }
+}
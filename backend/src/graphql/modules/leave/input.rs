@@ -0,0 +1,26 @@
+use async_graphql::InputObject;
+use chrono::NaiveDate;
+use shared::types::{
+    models::leave::leave_type::LeaveType, requests::leave::submit_leave_request::SubmitLeaveRequest,
+};
+
+#[derive(InputObject)]
+pub struct SubmitLeaveRequestInput {
+    pub leave_type: LeaveType,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub reason: String,
+    pub attachment_url: Option<String>,
+}
+
+impl SubmitLeaveRequestInput {
+    pub fn to_submit_leave_request(self) -> SubmitLeaveRequest {
+        SubmitLeaveRequest {
+            leave_type: self.leave_type,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            reason: self.reason,
+            attachment_url: self.attachment_url,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use async_graphql::{ID, SimpleObject};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::{
+    models::leave::{leave_status::LeaveStatus, leave_type::LeaveType},
+    responses::leave_request_response::LeaveRequestResponse,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct LeaveRequestObject {
+    pub id: ID,
+    pub organization_id: ID,
+    pub user_id: ID,
+    pub leave_type: LeaveType,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub days: i64,
+    pub reason: String,
+    pub attachment_url: Option<String>,
+    pub status: LeaveStatus,
+    pub reviewed_by: Option<ID>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<LeaveRequestResponse> for LeaveRequestObject {
+    fn from(l: LeaveRequestResponse) -> Self {
+        Self {
+            id: l.id.into(),
+            organization_id: l.organization_id.into(),
+            user_id: l.user_id.into(),
+            leave_type: l.leave_type,
+            start_date: l.start_date,
+            end_date: l.end_date,
+            days: l.days,
+            reason: l.reason,
+            attachment_url: l.attachment_url,
+            status: l.status,
+            reviewed_by: l.reviewed_by.map(ID::from),
+            reviewed_at: l.reviewed_at,
+            review_note: l.review_note,
+            created_at: l.created_at,
+            updated_at: l.updated_at,
+        }
+    }
+}
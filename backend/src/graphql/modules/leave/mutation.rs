@@ -0,0 +1,90 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_user, tenant_scope};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::leave::{input::SubmitLeaveRequestInput, model::LeaveRequestObject};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::{
+    models::user::permissions::Permission,
+    requests::leave::review_leave_request::ReviewLeaveRequest,
+};
+
+#[derive(Default)]
+pub struct LeaveMutation;
+
+#[Object]
+impl LeaveMutation {
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn submit_leave_request(
+        &self,
+        ctx: &Context<'_>,
+        input: SubmitLeaveRequestInput,
+    ) -> Result<LeaveRequestObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.leave_service;
+
+        let leave = svc
+            .submit_leave_request(
+                &caller.id,
+                &caller.organization_id,
+                input.to_submit_leave_request(),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(LeaveRequestObject::from(leave))
+    }
+
+    /// Members may withdraw their own requests while they are still pending.
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn cancel_leave_request(&self, ctx: &Context<'_>, id: ID) -> Result<LeaveRequestObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.leave_service;
+
+        let leave = svc
+            .cancel_leave_request(&caller.id, &id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(LeaveRequestObject::from(leave))
+    }
+
+    /// Approval marks every scheduled day in the range as excused.
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn approve_leave_request(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        note: Option<String>,
+    ) -> Result<LeaveRequestObject> {
+        review(ctx, id, true, note).await
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn reject_leave_request(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        note: Option<String>,
+    ) -> Result<LeaveRequestObject> {
+        review(ctx, id, false, note).await
+    }
+}
+
+async fn review(
+    ctx: &Context<'_>,
+    id: ID,
+    approve: bool,
+    note: Option<String>,
+) -> Result<LeaveRequestObject> {
+    let caller = current_user(ctx).await?;
+    let scope = tenant_scope(&caller).map_err(graphql_error)?;
+    let svc = &ctx.data::<GQLContext>()?.app_service.leave_service;
+
+    let leave = svc
+        .review_leave_request(scope, &caller.id, &id, ReviewLeaveRequest { approve, note })
+        .await
+        .map_err(|e| graphql_error(e.into()))?;
+
+    Ok(LeaveRequestObject::from(leave))
+}
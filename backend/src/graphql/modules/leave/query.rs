@@ -0,0 +1,49 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_user, tenant_scope};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::leave::model::LeaveRequestObject;
+use async_graphql::{Context, Object, Result};
+use shared::types::models::{leave::leave_status::LeaveStatus, user::permissions::Permission};
+
+#[derive(Default)]
+pub struct LeaveQuery;
+
+#[Object]
+impl LeaveQuery {
+    #[graphql(guard = "PermissionGuard::new(Permission::MarkAttendance)")]
+    async fn my_leave_requests(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<LeaveStatus>,
+    ) -> Result<Vec<LeaveRequestObject>> {
+        let caller = current_user(ctx).await?;
+        let scope = tenant_scope(&caller).map_err(graphql_error)?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.leave_service;
+
+        let requests = svc
+            .get_leave_requests(scope, Some(&caller.id), status)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(requests.into_iter().map(LeaveRequestObject::from).collect())
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn leave_requests(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<LeaveStatus>,
+    ) -> Result<Vec<LeaveRequestObject>> {
+        let caller = current_user(ctx).await?;
+        let scope = tenant_scope(&caller).map_err(graphql_error)?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.leave_service;
+
+        let requests = svc
+            .get_leave_requests(scope, None, status)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(requests.into_iter().map(LeaveRequestObject::from).collect())
+    }
+}
@@ -1 +1,11 @@
+pub mod attendance;
+pub mod auth;
+pub mod correction;
+pub mod device;
+pub mod geofence;
+pub mod holiday;
+pub mod leave;
+pub mod organization;
+pub mod schedule;
+pub mod subscription;
 pub mod user;
@@ -0,0 +1,81 @@
+use async_graphql::InputObject;
+use shared::types::{
+    models::{
+        attendance::geofence_policy::GeofencePolicy,
+        organization::organization_sort_field::OrganizationSortField,
+        sort_direction::SortDirection,
+    },
+    requests::organization::{
+        list_organizations_request::ListOrganizationsRequest,
+        register_organization_request::RegisterOrganizationRequest,
+        update_organization_request::UpdateOrganizationRequest,
+    },
+};
+
+#[derive(InputObject)]
+pub struct RegisterOrganizationInput {
+    pub name: String,
+    pub email: String,
+    #[graphql(default)]
+    pub logo_url: String,
+}
+
+impl RegisterOrganizationInput {
+    pub fn to_register_organization_request(self) -> RegisterOrganizationRequest {
+        RegisterOrganizationRequest {
+            name: self.name,
+            email: self.email,
+            logo_url: self.logo_url,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct UpdateOrganizationInput {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub logo_url: Option<String>,
+    pub geofence_policy: Option<GeofencePolicy>,
+    /// IANA name such as `Asia/Jakarta`.
+    pub timezone: Option<String>,
+}
+
+impl UpdateOrganizationInput {
+    pub fn to_update_organization_request(self) -> UpdateOrganizationRequest {
+        UpdateOrganizationRequest {
+            name: self.name,
+            email: self.email,
+            logo_url: self.logo_url,
+            geofence_policy: self.geofence_policy,
+            timezone: self.timezone,
+        }
+    }
+}
+
+/// `search` matches anywhere in the name or email, case-insensitively.
+#[derive(InputObject, Default)]
+pub struct OrganizationFilterInput {
+    pub search: Option<String>,
+}
+
+#[derive(InputObject, Default)]
+pub struct OrganizationSortInput {
+    #[graphql(default)]
+    pub field: OrganizationSortField,
+    #[graphql(default)]
+    pub direction: SortDirection,
+}
+
+impl OrganizationFilterInput {
+    pub fn to_list_organizations_request(
+        self,
+        sort: OrganizationSortInput,
+    ) -> ListOrganizationsRequest {
+        ListOrganizationsRequest {
+            search: self.search,
+            sort_by: sort.field,
+            direction: sort.direction,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::current_scope;
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::{attendance::model::AttendanceObject, user::model::UserObject};
+use async_graphql::{ComplexObject, Context, ID, Result, SimpleObject};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::{
+    models::{attendance::geofence_policy::GeofencePolicy, user::permissions::Permission},
+    responses::organization_response::OrganizationResponse,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+#[graphql(complex)]
+pub struct OrganizationObject {
+    pub id: ID,
+    pub name: String,
+    pub email: String,
+    pub owner_id: ID,
+    pub logo_url: String,
+    pub max_users: i32,
+    pub max_attendance_logs: i32,
+    pub geofence_policy: GeofencePolicy,
+    pub timezone: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[ComplexObject]
+impl OrganizationObject {
+    async fn owner(&self, ctx: &Context<'_>) -> Result<Option<UserObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
+
+        let owner = svc
+            .get_user_by_id(scope, &self.owner_id)
+            .await
+            .map_err(graphql_error)?;
+
+        Ok(owner.map(UserObject::from))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageUsers)")]
+    async fn members(&self, ctx: &Context<'_>) -> Result<Vec<UserObject>> {
+        let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
+
+        let members = svc
+            .get_users_by_organization(&self.id)
+            .await
+            .map_err(graphql_error)?;
+
+        Ok(members.into_iter().map(UserObject::from).collect())
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn attendances(&self, ctx: &Context<'_>) -> Result<Vec<AttendanceObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.attendance_service;
+
+        let attendances = svc
+            .get_attendances_for_org(scope, &self.id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(attendances
+            .into_iter()
+            .map(AttendanceObject::from)
+            .collect())
+    }
+}
+
+impl From<OrganizationResponse> for OrganizationObject {
+    fn from(o: OrganizationResponse) -> Self {
+        Self {
+            id: o.id.into(),
+            name: o.name,
+            email: o.email,
+            owner_id: o.owner_id.into(),
+            logo_url: o.logo_url,
+            max_users: o.max_users,
+            max_attendance_logs: o.max_attendance_logs,
+            geofence_policy: o.geofence_policy,
+            timezone: o.timezone,
+            created_at: o.created_at,
+            updated_at: o.updated_at,
+        }
+    }
+}
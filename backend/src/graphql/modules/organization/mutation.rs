@@ -0,0 +1,59 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::{AppError, graphql_error};
+use crate::graphql::middleware::auth::{current_scope, extract_user_id};
+use crate::graphql::middleware::guard::{NoOrganizationGuard, PermissionGuard};
+use crate::graphql::modules::organization::{
+    input::{RegisterOrganizationInput, UpdateOrganizationInput},
+    model::OrganizationObject,
+};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::models::user::permissions::Permission;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct OrganizationMutation;
+
+#[Object]
+impl OrganizationMutation {
+    /// Only callers without an organization may register one; they become its owner.
+    #[graphql(guard = "NoOrganizationGuard")]
+    async fn register_organization(
+        &self,
+        ctx: &Context<'_>,
+        input: RegisterOrganizationInput,
+    ) -> Result<OrganizationObject> {
+        let user_id = extract_user_id(ctx)?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.organization_service;
+
+        let owner_id = Uuid::parse_str(&user_id)
+            .map_err(|e| graphql_error(AppError::Validation(e.to_string())))?;
+
+        let created = svc
+            .create_organization(owner_id, input.to_register_organization_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(OrganizationObject::from(created))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageOrganization)")]
+    async fn update_organization(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        input: UpdateOrganizationInput,
+    ) -> Result<OrganizationObject> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.organization_service;
+
+        let org_id =
+            Uuid::parse_str(&id).map_err(|e| graphql_error(AppError::Validation(e.to_string())))?;
+
+        let updated = svc
+            .update_organization(scope, org_id, &input.to_update_organization_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(OrganizationObject::from(updated))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::{AppError, graphql_error};
+use crate::graphql::middleware::auth::current_scope;
+use crate::graphql::modules::organization::input::{
+    OrganizationFilterInput, OrganizationSortInput,
+};
+use crate::graphql::modules::organization::model::OrganizationObject;
+use crate::graphql::pagination::{PageConnection, paginate};
+use async_graphql::{Context, ID, Object, Result};
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct OrganizationQuery;
+
+#[Object]
+impl OrganizationQuery {
+    async fn organization(&self, ctx: &Context<'_>, id: ID) -> Result<Option<OrganizationObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.organization_service;
+
+        let org_id =
+            Uuid::parse_str(&id).map_err(|e| graphql_error(AppError::Validation(e.to_string())))?;
+
+        let org = svc
+            .get_organization_by_id(scope, org_id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(org.map(OrganizationObject::from))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn organizations(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] filter: OrganizationFilterInput,
+        #[graphql(default)] sort: OrganizationSortInput,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<PageConnection<OrganizationObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.organization_service;
+        let request = filter.to_list_organizations_request(sort);
+
+        paginate(ctx, after, before, first, last, |page| async move {
+            let orgs = svc
+                .list_organizations(scope, &request, page)
+                .await
+                .map_err(|e| graphql_error(e.into()))?;
+
+            Ok(orgs.map(OrganizationObject::from))
+        })
+        .await
+    }
+}
@@ -0,0 +1,44 @@
+use async_graphql::{ID, InputObject};
+use chrono::NaiveTime;
+use shared::types::requests::schedule::{
+    assign_shift_request::AssignShiftRequest, shift_request::ShiftRequest,
+};
+
+#[derive(InputObject)]
+pub struct ShiftInput {
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub weekdays: Vec<i32>,
+    #[graphql(default)]
+    pub grace_minutes: i32,
+}
+
+impl ShiftInput {
+    pub fn to_shift_request(self) -> ShiftRequest {
+        ShiftRequest {
+            name: self.name,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            weekdays: self.weekdays,
+            grace_minutes: self.grace_minutes,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AssignShiftInput {
+    pub shift_id: ID,
+    pub user_id: Option<ID>,
+    pub group_id: Option<ID>,
+}
+
+impl AssignShiftInput {
+    pub fn to_assign_shift_request(self) -> AssignShiftRequest {
+        AssignShiftRequest {
+            shift_id: self.shift_id.to_string(),
+            user_id: self.user_id.map(|id| id.to_string()),
+            group_id: self.group_id.map(|id| id.to_string()),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::current_scope;
+use async_graphql::{ComplexObject, Context, ID, Result, SimpleObject};
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::responses::{
+    group_response::GroupResponse, shift_assignment_response::ShiftAssignmentResponse,
+    shift_response::ShiftResponse,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+#[graphql(complex)]
+pub struct ShiftObject {
+    pub id: ID,
+    pub organization_id: ID,
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub weekdays: Vec<i32>,
+    pub grace_minutes: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[ComplexObject]
+impl ShiftObject {
+    async fn assignments(&self, ctx: &Context<'_>) -> Result<Vec<ShiftAssignmentObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        let assignments = svc
+            .get_assignments_for_shift(scope, &self.id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(assignments
+            .into_iter()
+            .map(ShiftAssignmentObject::from)
+            .collect())
+    }
+}
+
+impl From<ShiftResponse> for ShiftObject {
+    fn from(s: ShiftResponse) -> Self {
+        Self {
+            id: s.id.into(),
+            organization_id: s.organization_id.into(),
+            name: s.name,
+            start_time: s.start_time,
+            end_time: s.end_time,
+            weekdays: s.weekdays,
+            grace_minutes: s.grace_minutes,
+            created_at: s.created_at,
+            updated_at: s.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+#[graphql(complex)]
+pub struct GroupObject {
+    pub id: ID,
+    pub organization_id: ID,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[ComplexObject]
+impl GroupObject {
+    async fn member_ids(&self, ctx: &Context<'_>) -> Result<Vec<ID>> {
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        let member_ids = svc
+            .get_group_member_ids(&self.id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(member_ids.into_iter().map(ID::from).collect())
+    }
+}
+
+impl From<GroupResponse> for GroupObject {
+    fn from(g: GroupResponse) -> Self {
+        Self {
+            id: g.id.into(),
+            organization_id: g.organization_id.into(),
+            name: g.name,
+            created_at: g.created_at,
+            updated_at: g.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct ShiftAssignmentObject {
+    pub id: ID,
+    pub shift_id: ID,
+    pub user_id: Option<ID>,
+    pub group_id: Option<ID>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ShiftAssignmentResponse> for ShiftAssignmentObject {
+    fn from(a: ShiftAssignmentResponse) -> Self {
+        Self {
+            id: a.id.into(),
+            shift_id: a.shift_id.into(),
+            user_id: a.user_id.map(ID::from),
+            group_id: a.group_id.map(ID::from),
+            created_at: a.created_at,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::schedule::{
+    input::{AssignShiftInput, ShiftInput},
+    model::{GroupObject, ShiftAssignmentObject, ShiftObject},
+};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct ScheduleMutation;
+
+#[Object]
+impl ScheduleMutation {
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn create_shift(&self, ctx: &Context<'_>, input: ShiftInput) -> Result<ShiftObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        let created = svc
+            .create_shift(&caller.organization_id, input.to_shift_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(ShiftObject::from(created))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn update_shift(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        input: ShiftInput,
+    ) -> Result<ShiftObject> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        let updated = svc
+            .update_shift(scope, &id, input.to_shift_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(ShiftObject::from(updated))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn delete_shift(&self, ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        svc.delete_shift(scope, &id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn create_group(&self, ctx: &Context<'_>, name: String) -> Result<GroupObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        let created = svc
+            .create_group(&caller.organization_id, name)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(GroupObject::from(created))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn add_group_member(&self, ctx: &Context<'_>, group_id: ID, user_id: ID) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        svc.add_group_member(scope, &group_id, &user_id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn remove_group_member(
+        &self,
+        ctx: &Context<'_>,
+        group_id: ID,
+        user_id: ID,
+    ) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        svc.remove_group_member(scope, &group_id, &user_id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn assign_shift(
+        &self,
+        ctx: &Context<'_>,
+        input: AssignShiftInput,
+    ) -> Result<ShiftAssignmentObject> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        let assignment = svc
+            .assign_shift(scope, input.to_assign_shift_request())
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(ShiftAssignmentObject::from(assignment))
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageAttendance)")]
+    async fn unassign_shift(&self, ctx: &Context<'_>, assignment_id: ID) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        svc.unassign_shift(scope, &assignment_id)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::current_scope;
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::schedule::model::{GroupObject, ShiftObject};
+use async_graphql::{Context, Object, Result};
+use shared::types::models::user::permissions::Permission;
+
+#[derive(Default)]
+pub struct ScheduleQuery;
+
+#[Object]
+impl ScheduleQuery {
+    #[graphql(guard = "PermissionGuard::new(Permission::ViewAttendance)")]
+    async fn shifts(&self, ctx: &Context<'_>) -> Result<Vec<ShiftObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        let shifts = svc
+            .get_shifts(scope)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(shifts.into_iter().map(ShiftObject::from).collect())
+    }
+
+    #[graphql(guard = "PermissionGuard::new(Permission::ViewAttendance)")]
+    async fn groups(&self, ctx: &Context<'_>) -> Result<Vec<GroupObject>> {
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.schedule_service;
+
+        let groups = svc
+            .get_groups(scope)
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(groups.into_iter().map(GroupObject::from).collect())
+    }
+}
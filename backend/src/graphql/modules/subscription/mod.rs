@@ -0,0 +1,4 @@
+pub mod model;
+pub mod query;
+
+pub use query::SubscriptionQuery;
@@ -0,0 +1,37 @@
+use async_graphql::{ID, SimpleObject};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::types::{
+    models::subscription::{
+        subscription_plan::SubscriptionPlan, subscription_status::SubscriptionStatus,
+    },
+    responses::subscription_response::SubscriptionResponse,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SubscriptionObject {
+    pub id: ID,
+    pub user_id: ID,
+    pub plan: SubscriptionPlan,
+    pub status: SubscriptionStatus,
+    pub start_date: DateTime<Utc>,
+    /// Null for plans that never expire.
+    pub expiry_date: Option<DateTime<Utc>>,
+    pub read_access: bool,
+    pub write_access: bool,
+}
+
+impl From<SubscriptionResponse> for SubscriptionObject {
+    fn from(s: SubscriptionResponse) -> Self {
+        Self {
+            id: s.id.into(),
+            user_id: s.user_id.into(),
+            plan: s.plan,
+            status: s.status,
+            start_date: s.start_date,
+            expiry_date: s.expiry_date,
+            read_access: s.read_access,
+            write_access: s.write_access,
+        }
+    }
+}
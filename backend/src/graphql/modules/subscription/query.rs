@@ -0,0 +1,34 @@
+use crate::graphql::context::GQLContext;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::modules::subscription::model::SubscriptionObject;
+use async_graphql::{Context, ID, Object, Result};
+
+#[derive(Default)]
+pub struct SubscriptionQuery;
+
+#[Object]
+impl SubscriptionQuery {
+    /// Plan of the organization's owner; defaults to the caller's organization.
+    async fn subscription(
+        &self,
+        ctx: &Context<'_>,
+        organization_id: Option<ID>,
+    ) -> Result<SubscriptionObject> {
+        let scope = current_scope(ctx).await?;
+        let user = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.subscription_service;
+
+        let subscription = svc
+            .get_organization_subscription(
+                scope,
+                organization_id
+                    .as_deref()
+                    .map_or(user.organization_id.as_str(), String::as_str),
+            )
+            .await
+            .map_err(|e| graphql_error(e.into()))?;
+
+        Ok(SubscriptionObject::from(subscription))
+    }
+}
@@ -1,5 +1,13 @@
 use async_graphql::InputObject;
-use shared::types::requests::auth::register_request::RegisterRequest;
+use shared::types::{
+    models::{
+        sort_direction::SortDirection,
+        user::{role::Role, user_sort_field::UserSortField, user_status::UserStatus},
+    },
+    requests::{
+        auth::register_request::RegisterRequest, user::list_users_request::ListUsersRequest,
+    },
+};
 
 #[derive(InputObject)]
 pub struct RegisterInput {
@@ -14,6 +22,56 @@ impl RegisterInput {
             id: self.id,
             name: self.name,
             email: self.email,
+            password: None,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AddMemberInput {
+    pub name: String,
+    pub email: String,
+    pub password: Option<String>,
+    pub role: Role,
+}
+
+impl AddMemberInput {
+    pub fn to_register_request(self) -> (RegisterRequest, Role) {
+        let request = RegisterRequest {
+            id: None,
+            name: self.name,
+            email: self.email,
+            password: self.password,
+        };
+
+        (request, self.role)
+    }
+}
+
+/// `search` matches anywhere in the name or email, case-insensitively.
+#[derive(InputObject, Default)]
+pub struct UserFilterInput {
+    pub role: Option<Role>,
+    pub status: Option<UserStatus>,
+    pub search: Option<String>,
+}
+
+#[derive(InputObject, Default)]
+pub struct UserSortInput {
+    #[graphql(default)]
+    pub field: UserSortField,
+    #[graphql(default)]
+    pub direction: SortDirection,
+}
+
+impl UserFilterInput {
+    pub fn to_list_users_request(self, sort: UserSortInput) -> ListUsersRequest {
+        ListUsersRequest {
+            role: self.role,
+            status: self.status,
+            search: self.search,
+            sort_by: sort.field,
+            direction: sort.direction,
         }
     }
 }
@@ -2,7 +2,10 @@ use async_graphql::{ID, SimpleObject};
 use serde::{Deserialize, Serialize};
 use shared::types::{
     models::user::{role::Role, user_status::UserStatus},
-    responses::user_response::UserResponse,
+    responses::{
+        user_import_response::{UserImportResponse, UserImportRowError},
+        user_response::UserResponse,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
@@ -46,3 +49,46 @@ impl From<UserResponse> for UserObject {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct UserImportRowErrorObject {
+    pub row: i32,
+    pub email: Option<String>,
+    pub field: String,
+    pub message: String,
+}
+
+impl From<UserImportRowError> for UserImportRowErrorObject {
+    fn from(e: UserImportRowError) -> Self {
+        Self {
+            row: e.row as i32,
+            email: e.email,
+            field: e.field,
+            message: e.message,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct UserImportReportObject {
+    pub dry_run: bool,
+    pub total_rows: i32,
+    /// Created users, or in a dry run the users that would be created.
+    pub users: Vec<UserObject>,
+    pub errors: Vec<UserImportRowErrorObject>,
+}
+
+impl From<UserImportResponse> for UserImportReportObject {
+    fn from(r: UserImportResponse) -> Self {
+        Self {
+            dry_run: r.dry_run,
+            total_rows: r.total_rows as i32,
+            users: r.users.into_iter().map(UserObject::from).collect(),
+            errors: r
+                .errors
+                .into_iter()
+                .map(UserImportRowErrorObject::from)
+                .collect(),
+        }
+    }
+}
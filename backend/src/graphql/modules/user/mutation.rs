@@ -1,64 +1,100 @@
 use crate::graphql::context::GQLContext;
-use crate::graphql::error::{AppError, graphql_error};
-use crate::graphql::middleware::auth::extract_claims;
-use crate::graphql::modules::user::model::UserObject;
-use async_graphql::{Context, Object, Result};
-use shared::types::requests::auth::register_request::RegisterRequest;
+use crate::graphql::error::graphql_error;
+use crate::graphql::middleware::auth::{current_scope, current_user};
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::middleware::locale::current_messages;
+use crate::graphql::modules::user::{
+    input::AddMemberInput,
+    model::{UserImportReportObject, UserObject},
+};
+use async_graphql::{Context, ID, Object, Result};
+use shared::types::models::user::{permissions::Permission, role::Role};
 use shared::types::requests::user::update_user_request::UpdateUserRequest;
 
+#[derive(Default)]
 pub struct UserMutation;
 
 #[Object]
 impl UserMutation {
-    async fn register_user(
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageUsers)")]
+    async fn update_user(
         &self,
         ctx: &Context<'_>,
-        mut input: RegisterRequest,
+        email: String,
+        input: UpdateUserRequest,
     ) -> Result<UserObject> {
-        let claims = extract_claims(ctx)?;
+        let scope = current_scope(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
 
-        let user_id = claims
-            .get("sub")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| graphql_error(AppError::Unauthorized))?;
+        let updated = svc
+            .update_user(scope, &email, input)
+            .await
+            .map_err(graphql_error)?;
 
-        input.id = Some(user_id.to_string());
+        Ok(UserObject::from(updated))
+    }
 
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageUsers)")]
+    async fn delete_user(&self, ctx: &Context<'_>, email: String) -> Result<bool> {
+        let scope = current_scope(ctx).await?;
         let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
 
-        let user = svc
-            .register_user(input)
+        svc.delete_user(scope, &email)
             .await
-            .map_err(|_| graphql_error(AppError::Conflict("Email already registered".into())))?;
+            .map_err(graphql_error)?;
 
-        Ok(UserObject::from(user))
+        Ok(true)
     }
 
-    async fn update_user(
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageRoles)")]
+    async fn add_member(&self, ctx: &Context<'_>, input: AddMemberInput) -> Result<UserObject> {
+        let caller = current_user(ctx).await?;
+        let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
+
+        let (request, role) = input.to_register_request();
+        let member = svc
+            .add_member(&caller.organization_id, request, role)
+            .await
+            .map_err(graphql_error)?;
+
+        Ok(UserObject::from(member))
+    }
+
+    /// Creates users from a `name,email,role` CSV. Invalid rows are reported and skipped; with
+    /// `dryRun` nothing is written.
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageRoles)")]
+    async fn import_users(
         &self,
         ctx: &Context<'_>,
-        email: String,
-        input: UpdateUserRequest,
-    ) -> Result<UserObject> {
-        let claims = extract_claims(ctx)?;
+        csv: String,
+        #[graphql(default)] dry_run: bool,
+    ) -> Result<UserImportReportObject> {
+        let caller = current_user(ctx).await?;
         let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
 
-        let updated = svc
-            .update_user(&email, input)
+        let report = svc
+            .import_users(
+                &caller.organization_id,
+                &csv,
+                dry_run,
+                &current_messages(ctx),
+            )
             .await
-            .map_err(|e| graphql_error(AppError::Internal(e.to_string())))?;
+            .map_err(graphql_error)?;
 
-        Ok(UserObject::from(updated))
+        Ok(UserImportReportObject::from(report))
     }
 
-    async fn delete_user(&self, ctx: &Context<'_>, email: String) -> Result<bool> {
-        let claims = extract_claims(ctx)?;
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageRoles)")]
+    async fn assign_role(&self, ctx: &Context<'_>, user_id: ID, role: Role) -> Result<UserObject> {
+        let scope = current_scope(ctx).await?;
         let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
 
-        svc.delete_user(&email)
+        let updated = svc
+            .assign_role(scope, &user_id, role)
             .await
-            .map_err(|e| graphql_error(AppError::Internal(e.to_string())))?;
+            .map_err(graphql_error)?;
 
-        Ok(true)
+        Ok(UserObject::from(updated))
     }
 }
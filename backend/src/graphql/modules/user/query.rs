@@ -1,33 +1,52 @@
 use crate::graphql::context::GQLContext;
 use crate::graphql::error::{AppError, graphql_error};
-use crate::graphql::middleware::auth::extract_claims;
+use crate::graphql::middleware::auth::current_scope;
+use crate::graphql::middleware::guard::PermissionGuard;
+use crate::graphql::modules::user::input::{UserFilterInput, UserSortInput};
 use crate::graphql::modules::user::model::UserObject;
+use crate::graphql::pagination::{PageConnection, paginate};
 use async_graphql::{Context, Object, Result};
-use serde_json::Value;
+use shared::types::models::user::permissions::Permission;
 
 #[derive(Default)]
 pub struct UserQuery;
 
 #[Object]
 impl UserQuery {
-    async fn users(&self, ctx: &Context<'_>) -> Result<Vec<UserObject>> {
-        let claims = extract_claims(ctx)?;
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageUsers)")]
+    #[allow(clippy::too_many_arguments)]
+    async fn users(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] filter: UserFilterInput,
+        #[graphql(default)] sort: UserSortInput,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<PageConnection<UserObject>> {
+        let scope = current_scope(ctx).await?;
         let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
+        let request = filter.to_list_users_request(sort);
 
-        let users = svc
-            .get_all_users()
-            .await
-            .map_err(|e| graphql_error(AppError::Internal(e.to_string())))?;
+        paginate(ctx, after, before, first, last, |page| async move {
+            let users = svc
+                .list_users(scope, &request, page)
+                .await
+                .map_err(graphql_error)?;
 
-        Ok(users.into_iter().map(UserObject::from).collect())
+            Ok(users.map(UserObject::from))
+        })
+        .await
     }
 
+    #[graphql(guard = "PermissionGuard::new(Permission::ManageUsers)")]
     async fn user_by_email(&self, ctx: &Context<'_>, email: String) -> Result<Option<UserObject>> {
-        let claims = extract_claims(ctx)?;
+        let scope = current_scope(ctx).await?;
         let svc = &ctx.data::<GQLContext>()?.app_service.user_service;
 
         let user = svc
-            .get_user(&email)
+            .get_user(scope, &email)
             .await
             .map_err(|_| graphql_error(AppError::NotFound("User not found".into())))?;
 
@@ -0,0 +1,72 @@
+use async_graphql::{
+    Context, Result, SimpleObject,
+    connection::{self, Connection, Edge, OpaqueCursor},
+};
+use shared::types::{
+    requests::page_request::{PageCursor, PageRequest},
+    responses::page_response::PageResponse,
+};
+use std::future::Future;
+
+#[derive(SimpleObject)]
+pub struct ConnectionTotals {
+    /// Counts every matching row, so it is only computed when selected.
+    pub total_count: i32,
+}
+
+/// Relay connection whose cursors opaquely encode each row's sort key and id.
+pub type PageConnection<T> = Connection<OpaqueCursor<PageCursor>, T, ConnectionTotals>;
+
+/// Decodes the Relay arguments into a `PageRequest`, lets `load` fetch that window and wraps the
+/// result as a connection.
+pub async fn paginate<T, F, Fut>(
+    ctx: &Context<'_>,
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    load: F,
+) -> Result<PageConnection<T>>
+where
+    T: async_graphql::OutputType,
+    F: FnOnce(PageRequest) -> Fut,
+    Fut: Future<Output = Result<PageResponse<T>>>,
+{
+    let count_total = ctx.look_ahead().field("totalCount").exists();
+
+    connection::query(
+        after,
+        before,
+        first,
+        last,
+        |after: Option<OpaqueCursor<PageCursor>>,
+         before: Option<OpaqueCursor<PageCursor>>,
+         first,
+         last| async move {
+            let page = load(PageRequest {
+                after: after.map(|cursor| cursor.0),
+                before: before.map(|cursor| cursor.0),
+                first,
+                last,
+                count_total,
+            })
+            .await?;
+
+            let mut connection = Connection::with_additional_fields(
+                page.has_previous_page,
+                page.has_next_page,
+                ConnectionTotals {
+                    total_count: page.total_count.unwrap_or_default() as i32,
+                },
+            );
+            connection.edges.extend(
+                page.items
+                    .into_iter()
+                    .map(|(cursor, item)| Edge::new(OpaqueCursor(cursor), item)),
+            );
+
+            Ok::<_, async_graphql::Error>(connection)
+        },
+    )
+    .await
+}
@@ -1,13 +1,60 @@
 use crate::graphql::{
     context::GQLContext,
-    modules::user::{UserMutation, UserQuery},
+    modules::{
+        attendance::{AttendanceMutation, AttendanceQuery, AttendanceSubscription},
+        auth::AuthMutation,
+        correction::{CorrectionMutation, CorrectionQuery},
+        device::{DeviceMutation, DeviceQuery},
+        geofence::{GeofenceMutation, GeofenceQuery},
+        holiday::{HolidayMutation, HolidayQuery},
+        leave::{LeaveMutation, LeaveQuery},
+        organization::{OrganizationMutation, OrganizationQuery},
+        schedule::{ScheduleMutation, ScheduleQuery},
+        subscription::SubscriptionQuery,
+        user::{UserMutation, UserQuery},
+    },
 };
-use async_graphql::{EmptySubscription, Schema};
+use async_graphql::{MergedObject, MergedSubscription, Schema};
 
-pub type AppSchema = Schema<UserQuery, UserMutation, EmptySubscription>;
+#[derive(MergedObject, Default)]
+pub struct QueryRoot(
+    UserQuery,
+    AttendanceQuery,
+    OrganizationQuery,
+    ScheduleQuery,
+    GeofenceQuery,
+    DeviceQuery,
+    LeaveQuery,
+    CorrectionQuery,
+    HolidayQuery,
+    SubscriptionQuery,
+);
+
+#[derive(MergedObject, Default)]
+pub struct MutationRoot(
+    AuthMutation,
+    UserMutation,
+    AttendanceMutation,
+    OrganizationMutation,
+    ScheduleMutation,
+    GeofenceMutation,
+    DeviceMutation,
+    LeaveMutation,
+    CorrectionMutation,
+    HolidayMutation,
+);
+
+#[derive(MergedSubscription, Default)]
+pub struct SubscriptionRoot(AttendanceSubscription);
+
+pub type AppSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
 
 pub fn create_schema(ctx: GQLContext) -> AppSchema {
-    Schema::build(UserQuery, UserMutation, EmptySubscription)
-        .data(ctx)
-        .finish()
+    Schema::build(
+        QueryRoot::default(),
+        MutationRoot::default(),
+        SubscriptionRoot::default(),
+    )
+    .data(ctx)
+    .finish()
 }
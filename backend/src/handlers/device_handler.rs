@@ -0,0 +1,49 @@
+use crate::graphql::error::AppError;
+use crate::services::app_service::AppService;
+use crate::utils::locale_utils::get_lang;
+use actix_web::{HttpRequest, HttpResponse, web};
+use shared::{
+    prelude::MessageLookup,
+    types::{
+        requests::device::device_check_in_request::DeviceCheckInRequest,
+        responses::api_response::ApiResponse,
+    },
+    utils::locale_utils::{Messages, Namespace},
+};
+
+const DEVICE_KEY_HEADER: &str = "X-Device-Key";
+
+pub async fn device_check_in(
+    req: HttpRequest,
+    body: web::Json<DeviceCheckInRequest>,
+    app_service: web::Data<AppService>,
+) -> HttpResponse {
+    let messages = Messages::new(get_lang(&req));
+
+    let Some(api_key) = req
+        .headers()
+        .get(DEVICE_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return HttpResponse::Unauthorized().json(ApiResponse::<()>::error(
+            messages.get_message(Namespace::Device, "unauthorized"),
+            None,
+        ));
+    };
+
+    match app_service
+        .device_service
+        .check_in(api_key, body.into_inner())
+        .await
+    {
+        Ok(attendance) => HttpResponse::Ok().json(ApiResponse::success(
+            messages.get_message(Namespace::Device, "check_in.success"),
+            Some(attendance),
+        )),
+        Err(e) => {
+            let message = e.to_message(&messages);
+            HttpResponse::build(AppError::from(e).status_code())
+                .json(ApiResponse::<()>::error(message, None))
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::constants::COOKIE_NAME;
+use crate::graphql::error::AppError;
+use crate::graphql::middleware::auth::{tenant_scope, validate_token};
+use crate::repositories::tenant_scope::TenantScope;
+use crate::services::app_service::AppService;
+use crate::utils::locale_utils::get_lang;
+use actix_web::{HttpRequest, HttpResponse, http::header, web};
+use shared::{
+    prelude::MessageLookup,
+    types::{
+        models::user::permissions::Permission,
+        requests::attendance::export_attendance_request::ExportAttendanceRequest,
+        responses::{api_response::ApiResponse, user_response::UserResponse},
+    },
+    utils::locale_utils::{Messages, Namespace},
+};
+
+async fn resolve_caller(req: &HttpRequest, app_service: &AppService) -> Option<UserResponse> {
+    let token = req
+        .headers()
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .map(str::to_string)
+        .or_else(|| {
+            req.cookie(COOKIE_NAME.as_str())
+                .map(|cookie| cookie.value().to_string())
+        })?;
+
+    let claims = validate_token(&token).ok()?;
+    let user_id = claims.get("sub").and_then(|v| v.as_str())?;
+
+    app_service
+        .user_service
+        .get_user_by_id(TenantScope::AllOrganizations, user_id)
+        .await
+        .ok()?
+}
+
+/// `GET /api/exports/attendance?from=&to=&format=csv|xlsx[&organization_id=]`
+pub async fn export_attendance(
+    req: HttpRequest,
+    query: web::Query<ExportAttendanceRequest>,
+    app_service: web::Data<AppService>,
+) -> HttpResponse {
+    let messages = Messages::new(get_lang(&req));
+
+    let Some(caller) = resolve_caller(&req, &app_service).await else {
+        return HttpResponse::Unauthorized().json(ApiResponse::<()>::error(
+            messages.get_message(Namespace::Common, "unauthorized"),
+            None,
+        ));
+    };
+    let scope = match tenant_scope(&caller) {
+        Ok(scope) if caller.role.has_permission(Permission::ManageAttendance) => scope,
+        _ => {
+            return HttpResponse::Forbidden().json(ApiResponse::<()>::error(
+                messages.get_message(Namespace::Attendance, "export.forbidden"),
+                None,
+            ));
+        }
+    };
+
+    let request = query.into_inner();
+    let organization_id = request
+        .organization_id
+        .as_deref()
+        .unwrap_or(&caller.organization_id);
+
+    match app_service
+        .export_service
+        .export_attendance(scope, organization_id, &request, &messages)
+    {
+        Ok(chunks) => {
+            let filename = format!(
+                "attendance-{}-{}.{}",
+                request.from, request.to, request.format
+            );
+            HttpResponse::Ok()
+                .content_type(request.format.content_type())
+                .insert_header((
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{filename}\""),
+                ))
+                .streaming(chunks)
+        }
+        Err(e) => {
+            let message = e.to_message(&messages);
+            HttpResponse::build(AppError::from(e).status_code())
+                .json(ApiResponse::<()>::error(message, None))
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod device_handler;
+pub mod export_handler;
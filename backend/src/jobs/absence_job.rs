@@ -0,0 +1,37 @@
+use crate::services::attendance_service::AttendanceService;
+use chrono::Utc;
+use log::{error, info};
+use std::{sync::Arc, time::Duration as StdDuration};
+use tokio::time::{MissedTickBehavior, interval};
+
+const ABSENCE_JOB_INTERVAL: StdDuration = StdDuration::from_secs(15 * 60);
+
+pub fn spawn_absence_job(attendance_service: Arc<AttendanceService>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(ABSENCE_JOB_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            run_once(&attendance_service).await;
+        }
+    });
+}
+
+/// Each organization's yesterday and today are resolved in its own timezone by the query.
+/// Expired QR code redemptions are cleared on the same tick.
+async fn run_once(attendance_service: &AttendanceService) {
+    match attendance_service.materialize_absences(Utc::now()).await {
+        Ok(created) if !created.is_empty() => {
+            info!("Materialized {} absence(s)", created.len());
+        }
+        Ok(_) => {}
+        Err(e) => error!("Absence materialization failed: {e}"),
+    }
+
+    match attendance_service.prune_qr_redemptions(Utc::now()).await {
+        Ok(pruned) if pruned > 0 => info!("Pruned {pruned} expired QR code redemption(s)"),
+        Ok(_) => {}
+        Err(e) => error!("QR code redemption cleanup failed: {e}"),
+    }
+}
@@ -0,0 +1,2 @@
+pub mod absence_job;
+pub mod subscription_job;
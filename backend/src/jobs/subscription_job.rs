@@ -0,0 +1,26 @@
+use crate::services::subscription_service::SubscriptionService;
+use log::{error, info};
+use std::{sync::Arc, time::Duration as StdDuration};
+use tokio::time::{MissedTickBehavior, interval};
+
+const SUBSCRIPTION_JOB_INTERVAL: StdDuration = StdDuration::from_secs(60 * 60);
+
+pub fn spawn_subscription_job(subscription_service: Arc<SubscriptionService>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(SUBSCRIPTION_JOB_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            run_once(&subscription_service).await;
+        }
+    });
+}
+
+async fn run_once(subscription_service: &SubscriptionService) {
+    match subscription_service.refresh_statuses().await {
+        Ok(0) => {}
+        Ok(updated) => info!("Updated the status of {updated} subscription(s)"),
+        Err(e) => error!("Subscription status refresh failed: {e}"),
+    }
+}
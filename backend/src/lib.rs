@@ -1,6 +1,9 @@
 pub mod config;
 pub mod constants;
 pub mod graphql;
+pub mod handlers;
+pub mod jobs;
 pub mod repositories;
 pub mod services;
 pub mod utils;
+pub mod ws;
@@ -2,44 +2,81 @@ use std::sync::Arc;
 
 use actix_cors::Cors;
 use actix_web::HttpRequest;
+use actix_web::guard;
 use actix_web::web;
 use actix_web::{HttpResponse, web::Data};
 use async_graphql::http::{GraphQLPlaygroundConfig, playground_source};
-use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse, GraphQLSubscription};
+use attendx_backend::constants::{COOKIE_NAME, REFRESH_COOKIE_NAME, RUN_MIGRATIONS};
 use attendx_backend::graphql::context::GQLContext;
+use attendx_backend::graphql::middleware::auth::{RefreshCookie, on_connection_init};
 use attendx_backend::{
     config::database::Database,
     graphql::schema::{AppSchema, create_schema},
+    handlers::{device_handler::device_check_in, export_handler::export_attendance},
+    jobs::{absence_job::spawn_absence_job, subscription_job::spawn_subscription_job},
     repositories::app_repository::AppRepository,
     services::app_service::AppService,
+    utils::locale_utils::get_lang,
+    ws::attendance_ws::attendance_ws,
 };
 use dotenv::dotenv;
 use shuttle_actix_web::ShuttleActixWeb;
 
-use async_graphql::Request;
-
 pub async fn graphql_handler(
     schema: web::Data<AppSchema>,
     req: HttpRequest,
     gql_req: GraphQLRequest,
 ) -> GraphQLResponse {
-    let mut inner_req = gql_req.into_inner();
-
-    if let Some(auth_header) = req.headers().get("Authorization") {
-        if let Ok(header_str) = auth_header.to_str() {
-            if let Some(token) = header_str.strip_prefix("Bearer ") {
-                inner_req = inner_req.data(token.to_string());
-            }
-        }
+    let mut inner_req = gql_req.into_inner().data(get_lang(&req));
+
+    let token = req
+        .headers()
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .map(str::to_string)
+        .or_else(|| {
+            req.cookie(COOKIE_NAME.as_str())
+                .map(|cookie| cookie.value().to_string())
+        });
+
+    if let Some(token) = token {
+        inner_req = inner_req.data(token);
+    }
+
+    if let Some(cookie) = req.cookie(REFRESH_COOKIE_NAME.as_str()) {
+        inner_req = inner_req.data(RefreshCookie(cookie.value().to_string()));
     }
 
     schema.execute(inner_req).await.into()
 }
 
+pub async fn graphql_ws_handler(
+    schema: web::Data<AppSchema>,
+    req: HttpRequest,
+    payload: web::Payload,
+) -> actix_web::Result<HttpResponse> {
+    GraphQLSubscription::new(AppSchema::clone(&schema))
+        .on_connection_init(on_connection_init)
+        .start(&req, payload)
+}
+
+/// Clients differ in how they case the `Upgrade` value (`websocket`, `WebSocket`).
+fn is_websocket_upgrade(ctx: &guard::GuardContext<'_>) -> bool {
+    ctx.head()
+        .headers()
+        .get("upgrade")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case("websocket"))
+}
+
 async fn graphql_playground() -> HttpResponse {
     HttpResponse::Ok()
         .content_type("text/html; charset=utf-8")
-        .body(playground_source(GraphQLPlaygroundConfig::new("/graphql")))
+        .body(playground_source(
+            GraphQLPlaygroundConfig::new("/graphql").subscription_endpoint("/graphql"),
+        ))
 }
 
 #[shuttle_runtime::main]
@@ -49,9 +86,18 @@ async fn main()
     let _ = env_logger::try_init();
 
     let db = Arc::new(Database::new().await.expect("Failed to connect to DB"));
+    if *RUN_MIGRATIONS {
+        db.migrate()
+            .await
+            .expect("Failed to run database migrations");
+    }
     let app_repository = Arc::new(AppRepository::new(db.clone()));
     let app_service = Arc::new(AppService::new(app_repository).await);
 
+    spawn_absence_job(app_service.attendance_service.clone());
+    spawn_subscription_job(app_service.subscription_service.clone());
+
+    let app_service_data = Data::from(app_service.clone());
     let gql_ctx = GQLContext { app_service };
     let schema = Data::new(create_schema(gql_ctx));
 
@@ -64,13 +110,27 @@ async fn main()
             .max_age(3600);
 
         cfg.app_data(schema.clone());
+        cfg.app_data(app_service_data.clone());
 
         cfg.service(
             web::resource("/graphql")
                 .route(web::post().to(graphql_handler))
+                .route(
+                    web::get()
+                        .guard(guard::fn_guard(is_websocket_upgrade))
+                        .to(graphql_ws_handler),
+                )
                 .wrap(cors),
         );
 
+        cfg.service(web::resource("/ws/attendance").route(web::get().to(attendance_ws)));
+
+        cfg.service(web::resource("/api/devices/check-in").route(web::post().to(device_check_in)));
+
+        cfg.service(
+            web::resource("/api/exports/attendance").route(web::get().to(export_attendance)),
+        );
+
         cfg.service(web::resource("/playground").route(web::get().to(graphql_playground)));
     };
 
@@ -1,28 +1,56 @@
 use crate::{
     config::database::Database,
     repositories::{
-        attendance_repository::AttendanceRepository,
-        organization_repository::OrganizationRepository, user_repository::UserRepository,
+        attendance_repository::AttendanceRepository, auth_repository::AuthRepository,
+        correction_repository::CorrectionRepository, device_repository::DeviceRepository,
+        geofence_repository::GeofenceRepository, holiday_repository::HolidayRepository,
+        leave_repository::LeaveRepository, organization_repository::OrganizationRepository,
+        schedule_repository::ScheduleRepository, subscription_repository::SubscriptionRepository,
+        user_repository::UserRepository,
     },
 };
 use std::sync::Arc;
 
 pub struct AppRepository {
+    pub auth_repository: Arc<AuthRepository>,
     pub user_repository: Arc<UserRepository>,
     pub organization_repository: Arc<OrganizationRepository>,
     pub attendance_repository: Arc<AttendanceRepository>,
+    pub schedule_repository: Arc<ScheduleRepository>,
+    pub geofence_repository: Arc<GeofenceRepository>,
+    pub device_repository: Arc<DeviceRepository>,
+    pub leave_repository: Arc<LeaveRepository>,
+    pub correction_repository: Arc<CorrectionRepository>,
+    pub holiday_repository: Arc<HolidayRepository>,
+    pub subscription_repository: Arc<SubscriptionRepository>,
 }
 
 impl AppRepository {
     pub fn new(db: Arc<Database>) -> Self {
+        let auth_repository = Arc::new(AuthRepository::new(db.pool.clone()));
         let user_repository = Arc::new(UserRepository::new(db.pool.clone()));
         let organization_repository = Arc::new(OrganizationRepository::new(db.pool.clone()));
         let attendance_repository = Arc::new(AttendanceRepository::new(db.pool.clone()));
+        let schedule_repository = Arc::new(ScheduleRepository::new(db.pool.clone()));
+        let geofence_repository = Arc::new(GeofenceRepository::new(db.pool.clone()));
+        let device_repository = Arc::new(DeviceRepository::new(db.pool.clone()));
+        let leave_repository = Arc::new(LeaveRepository::new(db.pool.clone()));
+        let correction_repository = Arc::new(CorrectionRepository::new(db.pool.clone()));
+        let holiday_repository = Arc::new(HolidayRepository::new(db.pool.clone()));
+        let subscription_repository = Arc::new(SubscriptionRepository::new(db.pool.clone()));
 
         Self {
+            auth_repository,
             user_repository,
             organization_repository,
             attendance_repository,
+            schedule_repository,
+            geofence_repository,
+            device_repository,
+            leave_repository,
+            correction_repository,
+            holiday_repository,
+            subscription_repository,
         }
     }
 }
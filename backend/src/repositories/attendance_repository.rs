@@ -1,7 +1,44 @@
-use shared::models::attendance_model::Attendance;
-use sqlx::{Error, PgPool};
+use crate::repositories::{
+    listing::{SortKey, bind_page},
+    tenant_scope::TenantScope,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use futures::stream::BoxStream;
+use shared::{
+    models::{
+        attendance_export_model::AttendanceExportRow, attendance_model::Attendance,
+        attendance_summary_model::AttendanceSummary,
+    },
+    types::{
+        models::attendance::attendance_sort_field::AttendanceSortField,
+        requests::{
+            attendance::{
+                attendance_summary_request::AttendanceSummaryRequest,
+                list_attendances_request::ListAttendancesRequest,
+            },
+            page_request::{PageCursor, PageRequest},
+        },
+    },
+};
+use sqlx::{Error, PgExecutor, PgPool};
 use uuid::Uuid;
 
+const ATTENDANCE_LIST_FILTER: &str = "WHERE ($1::uuid IS NULL OR organization_id = $1)
+       AND ($2::uuid IS NULL OR organization_id = $2)
+       AND ($3::uuid IS NULL OR user_id = $3)
+       AND ($4::date IS NULL OR local_date >= $4)
+       AND ($5::date IS NULL OR local_date <= $5)
+       AND ($6::attendance_status IS NULL OR status = $6)
+       AND ($7::attendance_method IS NULL OR method = $7)";
+
+pub enum ClockIn {
+    Created(Box<Attendance>),
+    /// Filled a row created earlier for the day, e.g. a materialized absence.
+    Opened(Box<Attendance>),
+    AlreadyClockedIn,
+    QrCodeUsed,
+}
+
 pub struct AttendanceRepository {
     pub pool: PgPool,
 }
@@ -12,105 +49,541 @@ impl AttendanceRepository {
     }
 
     pub async fn create_attendance(&self, a: &Attendance) -> Result<Attendance, Error> {
-        sqlx::query_as::<_, Attendance>(
-            r#"
-            INSERT INTO attendances (
-                id, user_id, organization_id,
-                clock_in, clock_out, date,
-                method, status, attendance_type,
-                lat, long, created_at, updated_at
-            ) VALUES (
-                $1, $2, $3,
-                $4, $5, $6,
-                $7, $8, $9,
-                $10, $11, $12, $13
-            ) RETURNING *
-        "#,
+        insert_attendance(&self.pool, a).await
+    }
+
+    pub async fn is_member_of(&self, user_id: Uuid, organization_id: Uuid) -> Result<bool, Error> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS (SELECT 1 FROM users WHERE id = $1 AND organization_id = $2)",
         )
-        .bind(a.id)
-        .bind(a.user_id)
-        .bind(a.organization_id)
-        .bind(a.clock_in)
-        .bind(a.clock_out)
-        .bind(a.date)
-        .bind(a.method.clone())
-        .bind(a.status.clone())
-        .bind(a.attendance_type.clone())
-        .bind(a.lat)
-        .bind(a.long)
-        .bind(a.created_at)
-        .bind(a.updated_at)
+        .bind(user_id)
+        .bind(organization_id)
         .fetch_one(&self.pool)
         .await
     }
 
-    pub async fn get_attendance_by_id(&self, id: Uuid) -> Result<Option<Attendance>, Error> {
-        sqlx::query_as::<_, Attendance>("SELECT * FROM attendances WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await
+    pub async fn get_attendance_by_id(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+    ) -> Result<Option<Attendance>, Error> {
+        sqlx::query_as::<_, Attendance>(
+            "SELECT * FROM attendances
+             WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
     }
 
-    pub async fn get_all_attendances(&self) -> Result<Vec<Attendance>, Error> {
-        sqlx::query_as::<_, Attendance>("SELECT * FROM attendances")
-            .fetch_all(&self.pool)
-            .await
+    pub async fn get_all_attendances(&self, scope: TenantScope) -> Result<Vec<Attendance>, Error> {
+        sqlx::query_as::<_, Attendance>(
+            "SELECT * FROM attendances WHERE ($1::uuid IS NULL OR organization_id = $1)",
+        )
+        .bind(scope.organization_id())
+        .fetch_all(&self.pool)
+        .await
     }
 
     pub async fn get_all_attendances_for_user_in_org(
         &self,
+        scope: TenantScope,
         user_id: Uuid,
         org_id: Uuid,
     ) -> Result<Vec<Attendance>, Error> {
         sqlx::query_as::<_, Attendance>(
-            "SELECT * FROM attendances WHERE user_id = $1 AND organization_id = $2",
+            "SELECT * FROM attendances
+             WHERE user_id = $1 AND organization_id = $2
+               AND ($3::uuid IS NULL OR organization_id = $3)",
         )
         .bind(user_id)
         .bind(org_id)
+        .bind(scope.organization_id())
         .fetch_all(&self.pool)
         .await
     }
 
     pub async fn get_all_attendances_for_org(
         &self,
+        scope: TenantScope,
         org_id: Uuid,
     ) -> Result<Vec<Attendance>, Error> {
-        sqlx::query_as::<_, Attendance>("SELECT * FROM attendances WHERE organization_id = $1")
-            .bind(org_id)
-            .fetch_all(&self.pool)
-            .await
+        sqlx::query_as::<_, Attendance>(
+            "SELECT * FROM attendances
+             WHERE organization_id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(org_id)
+        .bind(scope.organization_id())
+        .fetch_all(&self.pool)
+        .await
     }
 
-    pub async fn update_attendance(&self, id: Uuid, a: &Attendance) -> Result<Attendance, Error> {
-        sqlx::query_as::<_, Attendance>(
-            r#"
-        UPDATE attendances SET
-            clock_in = $1, clock_out = $2, date = $3,
-            method = $4, status = $5, attendance_type = $6,
-            lat = $7, long = $8, updated_at = $9
-        WHERE id = $10
-        RETURNING *
-    "#,
+    /// `organization_id` and `user_id` are the parsed forms of the request's filters.
+    pub async fn count_attendances(
+        &self,
+        scope: TenantScope,
+        organization_id: Option<Uuid>,
+        user_id: Option<Uuid>,
+        request: &ListAttendancesRequest,
+    ) -> Result<i64, Error> {
+        sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM attendances {ATTENDANCE_LIST_FILTER}"
+        ))
+        .bind(scope.organization_id())
+        .bind(organization_id)
+        .bind(user_id)
+        .bind(request.from)
+        .bind(request.to)
+        .bind(request.status)
+        .bind(request.method)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn list_attendances(
+        &self,
+        scope: TenantScope,
+        organization_id: Option<Uuid>,
+        user_id: Option<Uuid>,
+        request: &ListAttendancesRequest,
+        page: &PageRequest,
+    ) -> Result<Vec<Attendance>, Error> {
+        let query = format!(
+            "SELECT * FROM attendances {ATTENDANCE_LIST_FILTER}{}",
+            attendance_sort_key(request).page_clause(page, 8)
+        );
+
+        let query = sqlx::query_as::<_, Attendance>(&query)
+            .bind(scope.organization_id())
+            .bind(organization_id)
+            .bind(user_id)
+            .bind(request.from)
+            .bind(request.to)
+            .bind(request.status)
+            .bind(request.method);
+
+        bind_page(query, page).fetch_all(&self.pool).await
+    }
+
+    pub async fn delete_attendance(&self, scope: TenantScope, id: Uuid) -> Result<(), Error> {
+        sqlx::query(
+            "DELETE FROM attendances WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
         )
-        .bind(a.clock_in)
-        .bind(a.clock_out)
-        .bind(a.date)
-        .bind(a.method.clone())
-        .bind(a.status.clone())
-        .bind(a.attendance_type.clone())
-        .bind(a.lat)
-        .bind(a.long)
-        .bind(a.updated_at)
         .bind(id)
-        .fetch_one(&self.pool)
+        .bind(scope.organization_id())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn find_attendance_for_day(
+        &self,
+        user_id: Uuid,
+        organization_id: Uuid,
+        local_date: NaiveDate,
+    ) -> Result<Option<Attendance>, Error> {
+        sqlx::query_as::<_, Attendance>(
+            "SELECT * FROM attendances
+             WHERE user_id = $1 AND organization_id = $2 AND local_date = $3
+             ORDER BY created_at
+             LIMIT 1",
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .bind(local_date)
+        .fetch_optional(&self.pool)
         .await
     }
 
-    pub async fn delete_attendance(&self, id: Uuid) -> Result<(), Error> {
-        sqlx::query("DELETE FROM attendances WHERE id = $1")
+    /// Wall-clock time of `at` in the organization's timezone.
+    pub async fn local_datetime(
+        &self,
+        organization_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Option<NaiveDateTime>, Error> {
+        sqlx::query_scalar::<_, NaiveDateTime>(
+            "SELECT $2 AT TIME ZONE timezone FROM organizations WHERE id = $1",
+        )
+        .bind(organization_id)
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Open record of the given day only; rows left open on earlier days are fixed through
+    /// correction requests instead of being closed by the next clock-out.
+    pub async fn find_open_attendance(
+        &self,
+        user_id: Uuid,
+        organization_id: Uuid,
+        local_date: NaiveDate,
+    ) -> Result<Option<Attendance>, Error> {
+        sqlx::query_as::<_, Attendance>(
+            "SELECT * FROM attendances
+             WHERE user_id = $1 AND organization_id = $2 AND local_date = $3
+               AND clock_in IS NOT NULL AND clock_out IS NULL
+             ORDER BY clock_in DESC
+             LIMIT 1",
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .bind(local_date)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Saves a clock-in into `existing` (today's row without one) or a new row, redeeming the QR
+    /// code in the same transaction so a failed clock-in leaves the code usable.
+    pub async fn clock_in(
+        &self,
+        existing: Option<Uuid>,
+        a: &Attendance,
+        qr_code: Option<&str>,
+    ) -> Result<ClockIn, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        if let Some(code) = qr_code {
+            let redeemed = sqlx::query(
+                "INSERT INTO qr_code_redemptions (code, user_id) VALUES ($1, $2)
+                 ON CONFLICT DO NOTHING",
+            )
+            .bind(code)
+            .bind(a.user_id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+            if redeemed == 0 {
+                tx.rollback().await?;
+                return Ok(ClockIn::QrCodeUsed);
+            }
+        }
+
+        let saved = match existing {
+            Some(id) => sqlx::query_as::<_, Attendance>(
+                "UPDATE attendances SET
+                    clock_in = $1, method = $2, status = $3, attendance_type = $4,
+                    lat = $5, long = $6, distance_meters = $7, outside_geofence = $8,
+                    updated_at = now()
+                 WHERE id = $9 AND clock_in IS NULL
+                 RETURNING *",
+            )
+            .bind(a.clock_in)
+            .bind(a.method)
+            .bind(a.status)
+            .bind(a.attendance_type)
+            .bind(a.lat)
+            .bind(a.long)
+            .bind(a.distance_meters)
+            .bind(a.outside_geofence)
             .bind(id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .map(|opened| ClockIn::Opened(Box::new(opened))),
+            None => match insert_attendance(&mut *tx, a).await {
+                Ok(created) => Some(ClockIn::Created(Box::new(created))),
+                Err(e)
+                    if e.as_database_error()
+                        .is_some_and(|db| db.is_unique_violation()) =>
+                {
+                    None
+                }
+                Err(e) => return Err(e),
+            },
+        };
+
+        let Some(saved) = saved else {
+            tx.rollback().await?;
+            return Ok(ClockIn::AlreadyClockedIn);
+        };
+
+        tx.commit().await?;
+        Ok(saved)
+    }
+
+    pub async fn close_clock_out(
+        &self,
+        id: Uuid,
+        clock_out: DateTime<Utc>,
+    ) -> Result<Option<Attendance>, Error> {
+        sqlx::query_as::<_, Attendance>(
+            "UPDATE attendances SET clock_out = $1, updated_at = now()
+             WHERE id = $2 AND clock_in IS NOT NULL AND clock_out IS NULL
+             RETURNING *",
+        )
+        .bind(clock_out)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn prune_qr_redemptions(&self, before: DateTime<Utc>) -> Result<u64, Error> {
+        sqlx::query("DELETE FROM qr_code_redemptions WHERE redeemed_at < $1")
+            .bind(before)
             .execute(&self.pool)
-            .await?;
-        Ok(())
+            .await
+            .map(|result| result.rows_affected())
     }
+
+    /// Inserts an absence for every scheduled member whose shift ended by `now` without a
+    /// record, looking at today and yesterday in each organization's own timezone.
+    pub async fn materialize_absences(&self, now: DateTime<Utc>) -> Result<Vec<Attendance>, Error> {
+        sqlx::query_as::<_, Attendance>(
+            r#"
+            INSERT INTO attendances (
+                user_id, organization_id, date, local_date, status, attendance_type
+            )
+            SELECT DISTINCT ON (u.id, o.id, d.day)
+                u.id, o.id, d.day::timestamp AT TIME ZONE o.timezone, d.day,
+                'absent', 'doublemark'
+            FROM organizations o
+            CROSS JOIN LATERAL (
+                SELECT ($1 AT TIME ZONE o.timezone)::date - k AS day
+                FROM generate_series(0, 1) k
+            ) d
+            JOIN shifts s ON s.organization_id = o.id
+            JOIN shift_assignments a ON a.shift_id = s.id
+            LEFT JOIN group_members gm ON gm.group_id = a.group_id
+            JOIN users u
+                ON u.id = COALESCE(a.user_id, gm.user_id)
+               AND u.organization_id = o.id
+            WHERE EXTRACT(ISODOW FROM d.day)::int = ANY(s.weekdays)
+              AND u.status = 'active'
+              AND (d.day + s.end_time
+                  + CASE WHEN s.end_time <= s.start_time THEN interval '1 day' ELSE interval '0' END
+                  ) AT TIME ZONE o.timezone <= $1
+              AND NOT EXISTS (
+                  SELECT 1 FROM attendances at
+                  WHERE at.user_id = u.id
+                    AND at.organization_id = o.id
+                    AND at.local_date = d.day
+              )
+              AND NOT EXISTS (
+                  SELECT 1 FROM leave_requests lr
+                  WHERE lr.user_id = u.id
+                    AND lr.organization_id = o.id
+                    AND lr.status = 'approved'
+                    AND d.day BETWEEN lr.start_date AND lr.end_date
+              )
+              AND NOT is_holiday(o.id, d.day)
+            ON CONFLICT DO NOTHING
+            RETURNING *
+        "#,
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Aggregates attendance between the local dates into day, week or month buckets, optionally
+    /// split per user. Attendance rate ignores excused days; clock-in times are averaged in the
+    /// organization's timezone.
+    pub async fn get_attendance_summary(
+        &self,
+        scope: TenantScope,
+        organization_id: Uuid,
+        user_id: Option<Uuid>,
+        group_by_user: bool,
+        request: &AttendanceSummaryRequest,
+    ) -> Result<Vec<AttendanceSummary>, Error> {
+        sqlx::query_as::<_, AttendanceSummary>(
+            r#"
+            SELECT
+                date_trunc($3, a.local_date::timestamp)::date AS period_start,
+                CASE WHEN $4 THEN a.user_id END AS user_id,
+                COUNT(*) FILTER (WHERE a.status = 'present') AS present,
+                COUNT(*) FILTER (WHERE a.status = 'late') AS late,
+                COUNT(*) FILTER (WHERE a.status = 'absent') AS absent,
+                COUNT(*) FILTER (WHERE a.status = 'excused') AS excused,
+                COUNT(*) FILTER (WHERE a.status IN ('present', 'late'))::float8
+                    / NULLIF(COUNT(*) FILTER (WHERE a.status <> 'excused'), 0) AS attendance_rate,
+                TIME '00:00'
+                    + AVG((a.clock_in AT TIME ZONE o.timezone)::time - TIME '00:00')
+                    AS average_clock_in,
+                COALESCE(
+                    SUM(EXTRACT(EPOCH FROM a.clock_out - a.clock_in))
+                        FILTER (WHERE a.clock_out > a.clock_in) / 3600,
+                    0
+                )::float8 AS total_hours
+            FROM attendances a
+            JOIN organizations o ON o.id = a.organization_id
+            WHERE a.organization_id = $1
+              AND ($2::uuid IS NULL OR a.user_id = $2)
+              AND a.local_date BETWEEN $5 AND $6
+              AND ($7::uuid IS NULL OR a.organization_id = $7)
+            GROUP BY 1, 2
+            ORDER BY 1, 2
+        "#,
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .bind(request.group_by.to_string())
+        .bind(group_by_user)
+        .bind(request.from)
+        .bind(request.to)
+        .bind(scope.organization_id())
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Rows are pulled from the server as the stream is polled rather than collected up front.
+    pub fn stream_attendance_export(
+        &self,
+        scope: TenantScope,
+        organization_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> BoxStream<'_, Result<AttendanceExportRow, Error>> {
+        sqlx::query_as::<_, AttendanceExportRow>(
+            r#"
+            SELECT
+                a.id, a.user_id, u.name AS user_name, u.email AS user_email, a.local_date,
+                a.clock_in AT TIME ZONE o.timezone AS clock_in,
+                a.clock_out AT TIME ZONE o.timezone AS clock_out,
+                (EXTRACT(EPOCH FROM a.clock_out - a.clock_in) / 3600)::float8 AS worked_hours,
+                a.status, a.attendance_type, a.method,
+                a.lat, a.long, a.distance_meters, a.outside_geofence
+            FROM attendances a
+            JOIN users u ON u.id = a.user_id
+            JOIN organizations o ON o.id = a.organization_id
+            WHERE a.organization_id = $1
+              AND a.local_date BETWEEN $2 AND $3
+              AND ($4::uuid IS NULL OR a.organization_id = $4)
+            ORDER BY a.local_date, u.name, a.clock_in
+        "#,
+        )
+        .bind(organization_id)
+        .bind(from)
+        .bind(to)
+        .bind(scope.organization_id())
+        .fetch(&self.pool)
+    }
+}
+
+fn attendance_sort_key(request: &ListAttendancesRequest) -> SortKey {
+    let (column, sql_type, nullable) = match request.sort_by {
+        AttendanceSortField::Date => ("local_date", "date", false),
+        AttendanceSortField::ClockIn => ("clock_in", "timestamptz", true),
+        AttendanceSortField::CreatedAt => ("created_at", "timestamptz", false),
+    };
+    SortKey {
+        column,
+        sql_type,
+        nullable,
+        direction: request.direction,
+    }
+}
+
+/// Cursor of `a` in a list sorted by `sort_by`, matching `attendance_sort_key`.
+pub fn attendance_cursor(sort_by: AttendanceSortField, a: &Attendance) -> PageCursor {
+    let key = match sort_by {
+        AttendanceSortField::Date => Some(a.local_date.to_string()),
+        AttendanceSortField::ClockIn => a.clock_in.map(|t| t.to_rfc3339()),
+        AttendanceSortField::CreatedAt => Some(a.created_at.to_rfc3339()),
+    };
+    PageCursor { key, id: a.id }
+}
+
+async fn insert_attendance<'e>(
+    executor: impl PgExecutor<'e>,
+    a: &Attendance,
+) -> Result<Attendance, Error> {
+    sqlx::query_as::<_, Attendance>(
+        r#"
+        INSERT INTO attendances (
+            id, user_id, organization_id,
+            clock_in, clock_out, date, local_date,
+            method, status, attendance_type,
+            lat, long, distance_meters, outside_geofence,
+            created_at, updated_at
+        ) VALUES (
+            $1, $2, $3,
+            $4, $5, $6, $7,
+            $8, $9, $10,
+            $11, $12, $13, $14,
+            $15, $16
+        ) RETURNING *
+    "#,
+    )
+    .bind(a.id)
+    .bind(a.user_id)
+    .bind(a.organization_id)
+    .bind(a.clock_in)
+    .bind(a.clock_out)
+    .bind(a.date)
+    .bind(a.local_date)
+    .bind(a.method)
+    .bind(a.status)
+    .bind(a.attendance_type)
+    .bind(a.lat)
+    .bind(a.long)
+    .bind(a.distance_meters)
+    .bind(a.outside_geofence)
+    .bind(a.created_at)
+    .bind(a.updated_at)
+    .fetch_one(executor)
+    .await
+}
+
+/// Marks absences between the local dates as excused and fills scheduled days that have no row yet.
+pub(crate) async fn excuse_days<'e>(
+    executor: impl PgExecutor<'e>,
+    user_id: Uuid,
+    organization_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<Attendance>, Error> {
+    sqlx::query_as::<_, Attendance>(
+        r#"
+        WITH updated AS (
+            UPDATE attendances
+            SET status = 'excused', updated_at = now()
+            WHERE user_id = $1
+              AND organization_id = $2
+              AND status = 'absent'
+              AND local_date BETWEEN $3 AND $4
+            RETURNING *
+        ),
+        inserted AS (
+            INSERT INTO attendances (
+                user_id, organization_id, date, local_date, status, attendance_type
+            )
+            SELECT $1, $2, d.day::timestamp AT TIME ZONE o.timezone, d.day,
+                'excused', 'doublemark'
+            FROM organizations o
+            CROSS JOIN LATERAL (
+                SELECT g::date AS day
+                FROM generate_series($3::date, $4::date, interval '1 day') g
+            ) d
+            WHERE o.id = $2
+              AND EXISTS (
+                SELECT 1 FROM shifts s
+                JOIN shift_assignments a ON a.shift_id = s.id
+                LEFT JOIN group_members gm ON gm.group_id = a.group_id
+                WHERE s.organization_id = $2
+                  AND COALESCE(a.user_id, gm.user_id) = $1
+                  AND EXTRACT(ISODOW FROM d.day)::int = ANY(s.weekdays)
+            )
+              AND NOT is_holiday($2, d.day)
+              AND NOT EXISTS (
+                SELECT 1 FROM attendances at
+                WHERE at.user_id = $1
+                  AND at.organization_id = $2
+                  AND at.local_date = d.day
+            )
+            ON CONFLICT DO NOTHING
+            RETURNING *
+        )
+        SELECT * FROM updated
+        UNION ALL
+        SELECT * FROM inserted
+    "#,
+    )
+    .bind(user_id)
+    .bind(organization_id)
+    .bind(start_date)
+    .bind(end_date)
+    .fetch_all(executor)
+    .await
 }
@@ -0,0 +1,103 @@
+use shared::models::refresh_token_model::RefreshToken;
+use sqlx::{Error, PgPool};
+use uuid::Uuid;
+
+pub struct AuthRepository {
+    pub pool: PgPool,
+}
+
+impl AuthRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_refresh_token(&self, token: &RefreshToken) -> Result<RefreshToken, Error> {
+        sqlx::query_as::<_, RefreshToken>(
+            "INSERT INTO refresh_tokens (id, user_id, family_id, token_hash, expires_at)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING *",
+        )
+        .bind(token.id)
+        .bind(token.user_id)
+        .bind(token.family_id)
+        .bind(&token.token_hash)
+        .bind(token.expires_at)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn find_refresh_token(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<RefreshToken>, Error> {
+        sqlx::query_as::<_, RefreshToken>("SELECT * FROM refresh_tokens WHERE token_hash = $1")
+            .bind(token_hash)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn rotate_refresh_token(
+        &self,
+        current_id: Uuid,
+        next: &RefreshToken,
+    ) -> Result<Option<RefreshToken>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let claimed = sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = now()
+             WHERE id = $1 AND revoked_at IS NULL",
+        )
+        .bind(current_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if claimed.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        let created = sqlx::query_as::<_, RefreshToken>(
+            "INSERT INTO refresh_tokens (id, user_id, family_id, token_hash, expires_at)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING *",
+        )
+        .bind(next.id)
+        .bind(next.user_id)
+        .bind(next.family_id)
+        .bind(&next.token_hash)
+        .bind(next.expires_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query("UPDATE refresh_tokens SET replaced_by = $1 WHERE id = $2")
+            .bind(created.id)
+            .bind(current_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(Some(created))
+    }
+
+    pub async fn revoke_family(&self, family_id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = now()
+             WHERE family_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(family_id)
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+
+    pub async fn revoke_all_for_user(&self, user_id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = now()
+             WHERE user_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+}
@@ -0,0 +1,187 @@
+use crate::repositories::tenant_scope::TenantScope;
+use chrono::Utc;
+use shared::{
+    models::{attendance_correction_model::AttendanceCorrection, attendance_model::Attendance},
+    types::models::attendance::correction_status::CorrectionStatus,
+};
+use sqlx::{Error, PgPool};
+use uuid::Uuid;
+
+pub enum CorrectionApproval {
+    Applied(Box<(AttendanceCorrection, Attendance)>),
+    NotPending,
+    /// The attendance changed after the correction was filed.
+    Stale,
+}
+
+pub struct CorrectionRepository {
+    pub pool: PgPool,
+}
+
+impl CorrectionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_correction(
+        &self,
+        c: &AttendanceCorrection,
+    ) -> Result<AttendanceCorrection, Error> {
+        sqlx::query_as::<_, AttendanceCorrection>(
+            r#"
+            INSERT INTO attendance_corrections (
+                id, organization_id, attendance_id, requested_by,
+                original_clock_in, original_clock_out, original_status,
+                proposed_clock_in, proposed_clock_out, proposed_status, reason
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+        "#,
+        )
+        .bind(c.id)
+        .bind(c.organization_id)
+        .bind(c.attendance_id)
+        .bind(c.requested_by)
+        .bind(c.original_clock_in)
+        .bind(c.original_clock_out)
+        .bind(c.original_status)
+        .bind(c.proposed_clock_in)
+        .bind(c.proposed_clock_out)
+        .bind(c.proposed_status)
+        .bind(&c.reason)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn get_corrections(
+        &self,
+        scope: TenantScope,
+        requested_by: Option<Uuid>,
+        status: Option<CorrectionStatus>,
+    ) -> Result<Vec<AttendanceCorrection>, Error> {
+        sqlx::query_as::<_, AttendanceCorrection>(
+            "SELECT * FROM attendance_corrections
+             WHERE ($1::uuid IS NULL OR organization_id = $1)
+               AND ($2::uuid IS NULL OR requested_by = $2)
+               AND ($3::correction_status IS NULL OR status = $3)
+             ORDER BY created_at DESC",
+        )
+        .bind(scope.organization_id())
+        .bind(requested_by)
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_correction_by_id(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+    ) -> Result<Option<AttendanceCorrection>, Error> {
+        sqlx::query_as::<_, AttendanceCorrection>(
+            "SELECT * FROM attendance_corrections
+             WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Marks the correction approved and applies it in one transaction, provided the
+    /// attendance still matches the snapshot taken when it was filed.
+    pub async fn approve_correction(
+        &self,
+        id: Uuid,
+        reviewed_by: Uuid,
+        review_note: Option<&str>,
+    ) -> Result<CorrectionApproval, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let claimed = sqlx::query_as::<_, AttendanceCorrection>(
+            "UPDATE attendance_corrections
+             SET status = 'approved', reviewed_by = $2, reviewed_at = $3, review_note = $4, updated_at = $3
+             WHERE id = $1 AND status = 'pending'
+             RETURNING *",
+        )
+        .bind(id)
+        .bind(reviewed_by)
+        .bind(Utc::now())
+        .bind(review_note)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(correction) = claimed else {
+            tx.rollback().await?;
+            return Ok(CorrectionApproval::NotPending);
+        };
+
+        let applied = sqlx::query_as::<_, Attendance>(
+            r#"
+            UPDATE attendances SET
+                clock_in = $2, clock_out = $3, status = $4, updated_at = now()
+            WHERE id = $1
+              AND clock_in IS NOT DISTINCT FROM $5
+              AND clock_out IS NOT DISTINCT FROM $6
+              AND status = $7
+            RETURNING *
+        "#,
+        )
+        .bind(correction.attendance_id)
+        .bind(correction.corrected_clock_in())
+        .bind(correction.corrected_clock_out())
+        .bind(correction.corrected_status())
+        .bind(correction.original_clock_in)
+        .bind(correction.original_clock_out)
+        .bind(correction.original_status)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(attendance) = applied else {
+            tx.rollback().await?;
+            return Ok(CorrectionApproval::Stale);
+        };
+
+        tx.commit().await?;
+        Ok(CorrectionApproval::Applied(Box::new((
+            correction, attendance,
+        ))))
+    }
+
+    pub async fn reject_correction(
+        &self,
+        id: Uuid,
+        reviewed_by: Uuid,
+        review_note: Option<&str>,
+    ) -> Result<Option<AttendanceCorrection>, Error> {
+        sqlx::query_as::<_, AttendanceCorrection>(
+            "UPDATE attendance_corrections
+             SET status = 'rejected', reviewed_by = $2, reviewed_at = $3, review_note = $4, updated_at = $3
+             WHERE id = $1 AND status = 'pending'
+             RETURNING *",
+        )
+        .bind(id)
+        .bind(reviewed_by)
+        .bind(Utc::now())
+        .bind(review_note)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn cancel_correction(
+        &self,
+        id: Uuid,
+        requested_by: Uuid,
+    ) -> Result<Option<AttendanceCorrection>, Error> {
+        sqlx::query_as::<_, AttendanceCorrection>(
+            "UPDATE attendance_corrections
+             SET status = 'cancelled', updated_at = now()
+             WHERE id = $1 AND requested_by = $2 AND status = 'pending'
+             RETURNING *",
+        )
+        .bind(id)
+        .bind(requested_by)
+        .fetch_optional(&self.pool)
+        .await
+    }
+}
@@ -0,0 +1,121 @@
+use crate::repositories::tenant_scope::TenantScope;
+use shared::models::{badge_model::Badge, device_model::Device};
+use sqlx::{Error, PgPool};
+use uuid::Uuid;
+
+pub struct DeviceRepository {
+    pub pool: PgPool,
+}
+
+impl DeviceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_device(&self, device: &Device) -> Result<Device, Error> {
+        sqlx::query_as::<_, Device>(
+            "INSERT INTO devices (id, organization_id, name, kind, api_key_hash)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING *",
+        )
+        .bind(device.id)
+        .bind(device.organization_id)
+        .bind(&device.name)
+        .bind(device.kind)
+        .bind(&device.api_key_hash)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn get_devices(&self, scope: TenantScope) -> Result<Vec<Device>, Error> {
+        sqlx::query_as::<_, Device>(
+            "SELECT * FROM devices
+             WHERE ($1::uuid IS NULL OR organization_id = $1)
+             ORDER BY name",
+        )
+        .bind(scope.organization_id())
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_device_by_key_hash(
+        &self,
+        api_key_hash: &str,
+    ) -> Result<Option<Device>, Error> {
+        sqlx::query_as::<_, Device>("SELECT * FROM devices WHERE api_key_hash = $1")
+            .bind(api_key_hash)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn touch_device(&self, id: Uuid) -> Result<(), Error> {
+        sqlx::query("UPDATE devices SET last_seen_at = now() WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn revoke_device(&self, scope: TenantScope, id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "UPDATE devices SET revoked_at = now(), updated_at = now()
+             WHERE id = $1 AND revoked_at IS NULL
+               AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+
+    /// Inserts the badge only if the user belongs to the badge's organization.
+    pub async fn create_badge(&self, badge: &Badge) -> Result<Option<Badge>, Error> {
+        sqlx::query_as::<_, Badge>(
+            "INSERT INTO badges (id, organization_id, user_id, uid)
+             SELECT $1, $2, u.id, $4 FROM users u
+             WHERE u.id = $3 AND u.organization_id = $2
+             RETURNING *",
+        )
+        .bind(badge.id)
+        .bind(badge.organization_id)
+        .bind(badge.user_id)
+        .bind(&badge.uid)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn get_badges(&self, scope: TenantScope) -> Result<Vec<Badge>, Error> {
+        sqlx::query_as::<_, Badge>(
+            "SELECT * FROM badges
+             WHERE ($1::uuid IS NULL OR organization_id = $1)
+             ORDER BY created_at",
+        )
+        .bind(scope.organization_id())
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_badge_by_uid(
+        &self,
+        organization_id: Uuid,
+        uid: &str,
+    ) -> Result<Option<Badge>, Error> {
+        sqlx::query_as::<_, Badge>("SELECT * FROM badges WHERE organization_id = $1 AND uid = $2")
+            .bind(organization_id)
+            .bind(uid)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn delete_badge(&self, scope: TenantScope, id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "DELETE FROM badges WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+}
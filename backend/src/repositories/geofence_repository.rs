@@ -0,0 +1,108 @@
+use crate::repositories::tenant_scope::TenantScope;
+use shared::{
+    models::geofence_model::Geofence, types::models::attendance::geofence_policy::GeofencePolicy,
+};
+use sqlx::{Error, PgPool, types::Json};
+use uuid::Uuid;
+
+pub struct GeofenceRepository {
+    pub pool: PgPool,
+}
+
+impl GeofenceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_geofence(&self, geofence: &Geofence) -> Result<Geofence, Error> {
+        sqlx::query_as::<_, Geofence>(
+            "INSERT INTO geofences
+                (id, organization_id, name, center_lat, center_long, radius_meters, polygon)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING *",
+        )
+        .bind(geofence.id)
+        .bind(geofence.organization_id)
+        .bind(&geofence.name)
+        .bind(geofence.center_lat)
+        .bind(geofence.center_long)
+        .bind(geofence.radius_meters)
+        .bind(geofence.polygon.as_ref().map(Json))
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn get_geofences(&self, scope: TenantScope) -> Result<Vec<Geofence>, Error> {
+        sqlx::query_as::<_, Geofence>(
+            "SELECT * FROM geofences
+             WHERE ($1::uuid IS NULL OR organization_id = $1)
+             ORDER BY name",
+        )
+        .bind(scope.organization_id())
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_geofence_by_id(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+    ) -> Result<Option<Geofence>, Error> {
+        sqlx::query_as::<_, Geofence>(
+            "SELECT * FROM geofences
+             WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn update_geofence(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+        geofence: &Geofence,
+    ) -> Result<Option<Geofence>, Error> {
+        sqlx::query_as::<_, Geofence>(
+            "UPDATE geofences SET
+                name = $1, center_lat = $2, center_long = $3,
+                radius_meters = $4, polygon = $5, updated_at = now()
+             WHERE id = $6 AND ($7::uuid IS NULL OR organization_id = $7)
+             RETURNING *",
+        )
+        .bind(&geofence.name)
+        .bind(geofence.center_lat)
+        .bind(geofence.center_long)
+        .bind(geofence.radius_meters)
+        .bind(geofence.polygon.as_ref().map(Json))
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn delete_geofence(&self, scope: TenantScope, id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "DELETE FROM geofences WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+
+    pub async fn get_geofence_policy(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<GeofencePolicy, Error> {
+        sqlx::query_scalar::<_, GeofencePolicy>(
+            "SELECT geofence_policy FROM organizations WHERE id = $1",
+        )
+        .bind(organization_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map(Option::unwrap_or_default)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::repositories::tenant_scope::TenantScope;
+use chrono::NaiveDate;
+use shared::models::holiday_model::Holiday;
+use sqlx::{Error, PgPool};
+use uuid::Uuid;
+
+pub struct HolidayRepository {
+    pub pool: PgPool,
+}
+
+impl HolidayRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_holiday(&self, holiday: &Holiday) -> Result<Holiday, Error> {
+        sqlx::query_as::<_, Holiday>(
+            "INSERT INTO holidays (id, organization_id, name, date, recurring)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING *",
+        )
+        .bind(holiday.id)
+        .bind(holiday.organization_id)
+        .bind(&holiday.name)
+        .bind(holiday.date)
+        .bind(holiday.recurring)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// One-off holidays inside the range plus every recurring holiday that started by its end.
+    pub async fn get_holidays(
+        &self,
+        scope: TenantScope,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<Holiday>, Error> {
+        sqlx::query_as::<_, Holiday>(
+            "SELECT * FROM holidays
+             WHERE ($1::uuid IS NULL OR organization_id = $1)
+               AND ($3::date IS NULL OR date <= $3)
+               AND (recurring OR $2::date IS NULL OR date >= $2)
+             ORDER BY date",
+        )
+        .bind(scope.organization_id())
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn is_holiday(&self, organization_id: Uuid, day: NaiveDate) -> Result<bool, Error> {
+        sqlx::query_scalar::<_, bool>("SELECT is_holiday($1, $2)")
+            .bind(organization_id)
+            .bind(day)
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn delete_holiday(&self, scope: TenantScope, id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "DELETE FROM holidays
+             WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+
+    /// Upserts by date so re-importing the same calendar is idempotent.
+    pub async fn import_holidays(&self, holidays: &[Holiday]) -> Result<Vec<Holiday>, Error> {
+        let mut tx = self.pool.begin().await?;
+        let mut imported = Vec::with_capacity(holidays.len());
+
+        for holiday in holidays {
+            let saved = sqlx::query_as::<_, Holiday>(
+                "INSERT INTO holidays (id, organization_id, name, date, recurring, source_uid)
+                 VALUES ($1, $2, $3, $4, $5, $6)
+                 ON CONFLICT (organization_id, date) DO UPDATE SET
+                    name = EXCLUDED.name,
+                    recurring = EXCLUDED.recurring,
+                    source_uid = EXCLUDED.source_uid,
+                    updated_at = now()
+                 RETURNING *",
+            )
+            .bind(holiday.id)
+            .bind(holiday.organization_id)
+            .bind(&holiday.name)
+            .bind(holiday.date)
+            .bind(holiday.recurring)
+            .bind(&holiday.source_uid)
+            .fetch_one(&mut *tx)
+            .await?;
+            imported.push(saved);
+        }
+
+        tx.commit().await?;
+        Ok(imported)
+    }
+}
@@ -0,0 +1,158 @@
+use crate::repositories::{attendance_repository::excuse_days, tenant_scope::TenantScope};
+use chrono::{NaiveDate, Utc};
+use shared::{
+    models::{attendance_model::Attendance, leave_request_model::LeaveRequest},
+    types::models::leave::leave_status::LeaveStatus,
+};
+use sqlx::{Error, PgPool};
+use uuid::Uuid;
+
+pub struct LeaveRepository {
+    pub pool: PgPool,
+}
+
+impl LeaveRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_leave_request(&self, leave: &LeaveRequest) -> Result<LeaveRequest, Error> {
+        sqlx::query_as::<_, LeaveRequest>(
+            "INSERT INTO leave_requests
+                (id, organization_id, user_id, leave_type, start_date, end_date, reason, attachment_url)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+             RETURNING *",
+        )
+        .bind(leave.id)
+        .bind(leave.organization_id)
+        .bind(leave.user_id)
+        .bind(leave.leave_type)
+        .bind(leave.start_date)
+        .bind(leave.end_date)
+        .bind(&leave.reason)
+        .bind(&leave.attachment_url)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn get_leave_requests(
+        &self,
+        scope: TenantScope,
+        user_id: Option<Uuid>,
+        status: Option<LeaveStatus>,
+    ) -> Result<Vec<LeaveRequest>, Error> {
+        sqlx::query_as::<_, LeaveRequest>(
+            "SELECT * FROM leave_requests
+             WHERE ($1::uuid IS NULL OR organization_id = $1)
+               AND ($2::uuid IS NULL OR user_id = $2)
+               AND ($3::leave_status IS NULL OR status = $3)
+             ORDER BY start_date DESC, created_at DESC",
+        )
+        .bind(scope.organization_id())
+        .bind(user_id)
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_leave_request_by_id(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+    ) -> Result<Option<LeaveRequest>, Error> {
+        sqlx::query_as::<_, LeaveRequest>(
+            "SELECT * FROM leave_requests
+             WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Pending or approved requests of the user that share at least one day with the range.
+    pub async fn has_overlapping_leave(
+        &self,
+        user_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<bool, Error> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS (
+                SELECT 1 FROM leave_requests
+                WHERE user_id = $1
+                  AND status IN ('pending', 'approved')
+                  AND start_date <= $3 AND end_date >= $2
+             )",
+        )
+        .bind(user_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// Only transitions requests that are still pending. Approving excuses the leave's days in
+    /// the same transaction, returning the affected attendance rows.
+    pub async fn review_leave_request(
+        &self,
+        id: Uuid,
+        status: LeaveStatus,
+        reviewed_by: Uuid,
+        review_note: Option<&str>,
+    ) -> Result<Option<(LeaveRequest, Vec<Attendance>)>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let reviewed = sqlx::query_as::<_, LeaveRequest>(
+            "UPDATE leave_requests
+             SET status = $2, reviewed_by = $3, reviewed_at = $4, review_note = $5, updated_at = $4
+             WHERE id = $1 AND status = 'pending'
+             RETURNING *",
+        )
+        .bind(id)
+        .bind(status)
+        .bind(reviewed_by)
+        .bind(Utc::now())
+        .bind(review_note)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(reviewed) = reviewed else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        let excused = if reviewed.status == LeaveStatus::Approved {
+            excuse_days(
+                &mut *tx,
+                reviewed.user_id,
+                reviewed.organization_id,
+                reviewed.start_date,
+                reviewed.end_date,
+            )
+            .await?
+        } else {
+            Vec::new()
+        };
+
+        tx.commit().await?;
+        Ok(Some((reviewed, excused)))
+    }
+
+    pub async fn cancel_leave_request(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<LeaveRequest>, Error> {
+        sqlx::query_as::<_, LeaveRequest>(
+            "UPDATE leave_requests
+             SET status = 'cancelled', updated_at = now()
+             WHERE id = $1 AND user_id = $2 AND status = 'pending'
+             RETURNING *",
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+}
@@ -0,0 +1,95 @@
+use shared::types::{
+    models::sort_direction::SortDirection,
+    requests::page_request::{PageCursor, PageRequest},
+};
+use sqlx::{Postgres, postgres::PgArguments, query::QueryAs};
+
+/// A whitelisted sort column, the Postgres type its cursor keys are cast back to, and whether
+/// it can be NULL. NULLs sort last in either direction, and `id` breaks ties.
+pub struct SortKey {
+    pub column: &'static str,
+    pub sql_type: &'static str,
+    pub nullable: bool,
+    pub direction: SortDirection,
+}
+
+impl SortKey {
+    /// Keyset conditions for the page's cursors followed by `ORDER BY` and `LIMIT`, to append
+    /// to a `WHERE` clause. Parameters are numbered from `first_param`; bind them with
+    /// `bind_page`.
+    pub fn page_clause(&self, page: &PageRequest, first_param: usize) -> String {
+        let mut next = first_param;
+        let mut clause = String::new();
+
+        for (cursor, following) in [(&page.after, true), (&page.before, false)] {
+            if cursor.is_some() {
+                clause.push_str(" AND ");
+                clause.push_str(&self.condition(following, next));
+                next += 2;
+            }
+        }
+
+        clause.push_str(&format!(
+            " ORDER BY {} LIMIT ${next}",
+            self.order_by(page.backwards())
+        ));
+        clause
+    }
+
+    /// Rows following (or preceding) the cursor bound at `$param` (key) and `$param + 1` (id).
+    fn condition(&self, following: bool, param: usize) -> String {
+        let (column, sql_type) = (self.column, self.sql_type);
+        let ascending = self.direction == SortDirection::Asc;
+        let op = if ascending == following { ">" } else { "<" };
+        let (key, id) = (format!("${param}::{sql_type}"), format!("${}", param + 1));
+
+        if !self.nullable {
+            return format!("({column}, id) {op} ({key}, {id})");
+        }
+        if following {
+            format!(
+                "(CASE WHEN {key} IS NULL THEN {column} IS NULL AND id {op} {id} \
+                 ELSE {column} IS NULL OR ({column}, id) {op} ({key}, {id}) END)"
+            )
+        } else {
+            format!(
+                "(CASE WHEN {key} IS NULL THEN {column} IS NOT NULL OR id {op} {id} \
+                 ELSE ({column}, id) {op} ({key}, {id}) END)"
+            )
+        }
+    }
+
+    /// `ORDER BY` body; `reverse` reads the list from its end.
+    fn order_by(&self, reverse: bool) -> String {
+        let keyword = if (self.direction == SortDirection::Asc) != reverse {
+            "ASC"
+        } else {
+            "DESC"
+        };
+        let nulls = if reverse { "FIRST" } else { "LAST" };
+        format!("{} {keyword} NULLS {nulls}, id {keyword}", self.column)
+    }
+}
+
+/// Binds the parameters of `SortKey::page_clause` in the same order.
+pub fn bind_page<'q, O>(
+    mut query: QueryAs<'q, Postgres, O, PgArguments>,
+    page: &PageRequest,
+) -> QueryAs<'q, Postgres, O, PgArguments> {
+    for cursor in [&page.after, &page.before].into_iter().flatten() {
+        let PageCursor { key, id } = cursor.clone();
+        query = query.bind(key).bind(id);
+    }
+    query.bind((page.limit() + 1) as i64)
+}
+
+/// `ILIKE` pattern matching `search` anywhere, with its own wildcards escaped.
+pub fn contains_pattern(search: Option<&str>) -> Option<String> {
+    search.map(str::trim).filter(|s| !s.is_empty()).map(|s| {
+        let escaped = s
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        format!("%{escaped}%")
+    })
+}
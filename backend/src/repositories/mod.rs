@@ -1,4 +1,14 @@
 pub mod app_repository;
 pub mod attendance_repository;
+pub mod auth_repository;
+pub mod correction_repository;
+pub mod device_repository;
+pub mod geofence_repository;
+pub mod holiday_repository;
+pub mod leave_repository;
+pub mod listing;
 pub mod organization_repository;
+pub mod schedule_repository;
+pub mod subscription_repository;
+pub mod tenant_scope;
 pub mod user_repository;
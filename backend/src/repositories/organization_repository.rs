@@ -1,8 +1,22 @@
-use shared::models::organization_model::Organization;
-use shared::types::requests::organization::update_organization_request::UpdateOrganizationRequest;
+use crate::repositories::{
+    listing::{SortKey, bind_page, contains_pattern},
+    tenant_scope::TenantScope,
+};
+use shared::models::{
+    organization_model::Organization, subscription_model::Subscription, user_model::NO_ORGANIZATION,
+};
+use shared::types::models::organization::organization_sort_field::OrganizationSortField;
+use shared::types::requests::organization::{
+    list_organizations_request::ListOrganizationsRequest,
+    update_organization_request::UpdateOrganizationRequest,
+};
+use shared::types::requests::page_request::{PageCursor, PageRequest};
 use sqlx::{Error, PgPool};
 use uuid::Uuid;
 
+const ORGANIZATION_LIST_FILTER: &str = "WHERE ($1::uuid IS NULL OR id = $1)
+       AND ($2::text IS NULL OR name ILIKE $2 OR email ILIKE $2)";
+
 pub struct OrganizationRepository {
     pub pool: PgPool,
 }
@@ -12,27 +26,82 @@ impl OrganizationRepository {
         Self { pool }
     }
 
-    pub async fn create_organization(&self, org: &Organization) -> Result<Organization, Error> {
-        sqlx::query_as::<_, Organization>(
-            "INSERT INTO organizations (id, name, email, logo_url, created_at, updated_at)
-             VALUES ($1, $2, $3, $4, $5, $6)
+    /// Creates the organization, moves its owner into it as `orgowner` and starts their
+    /// subscription, all in one transaction. Returns `None` when the owner already belongs to an
+    /// organization.
+    pub async fn register_organization(
+        &self,
+        org: &Organization,
+        subscription: &Subscription,
+    ) -> Result<Option<Organization>, Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let claimed = sqlx::query(
+            "UPDATE users SET organization_id = $1, role = 'orgowner', updated_at = now()
+             WHERE id = $2 AND organization_id = $3",
+        )
+        .bind(org.id)
+        .bind(org.owner_id)
+        .bind(NO_ORGANIZATION)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if claimed == 0 {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        let created = sqlx::query_as::<_, Organization>(
+            "INSERT INTO organizations (
+                id, name, email, owner_id, logo_url,
+                max_users, max_attendance_logs, created_at, updated_at
+             )
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
              RETURNING *",
         )
         .bind(org.id)
         .bind(&org.name)
         .bind(&org.email)
+        .bind(org.owner_id)
         .bind(&org.logo_url)
+        .bind(org.max_users)
+        .bind(org.max_attendance_logs)
         .bind(org.created_at)
         .bind(org.updated_at)
-        .fetch_one(&self.pool)
-        .await
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO subscriptions (id, user_id, plan, status, start_date, expiry_date)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT (user_id) DO NOTHING",
+        )
+        .bind(subscription.id)
+        .bind(subscription.user_id)
+        .bind(subscription.plan)
+        .bind(subscription.status)
+        .bind(subscription.start_date)
+        .bind(subscription.expiry_date)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(created))
     }
 
-    pub async fn find_organization_by_id(&self, id: Uuid) -> Result<Option<Organization>, Error> {
-        sqlx::query_as::<_, Organization>("SELECT * FROM organizations WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await
+    pub async fn find_organization_by_id(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+    ) -> Result<Option<Organization>, Error> {
+        sqlx::query_as::<_, Organization>(
+            "SELECT * FROM organizations WHERE id = $1 AND ($2::uuid IS NULL OR id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
     }
 
     pub async fn find_organization_by_email(
@@ -45,32 +114,103 @@ impl OrganizationRepository {
             .await
     }
 
-    pub async fn get_all_organizations(&self) -> Result<Vec<Organization>, Error> {
-        sqlx::query_as::<_, Organization>("SELECT * FROM organizations")
-            .fetch_all(&self.pool)
-            .await
+    pub async fn count_organizations(
+        &self,
+        scope: TenantScope,
+        request: &ListOrganizationsRequest,
+    ) -> Result<i64, Error> {
+        sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM organizations {ORGANIZATION_LIST_FILTER}"
+        ))
+        .bind(scope.organization_id())
+        .bind(contains_pattern(request.search.as_deref()))
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn list_organizations(
+        &self,
+        scope: TenantScope,
+        request: &ListOrganizationsRequest,
+        page: &PageRequest,
+    ) -> Result<Vec<Organization>, Error> {
+        let query = format!(
+            "SELECT * FROM organizations {ORGANIZATION_LIST_FILTER}{}",
+            organization_sort_key(request).page_clause(page, 3)
+        );
+
+        let query = sqlx::query_as::<_, Organization>(&query)
+            .bind(scope.organization_id())
+            .bind(contains_pattern(request.search.as_deref()));
+
+        bind_page(query, page).fetch_all(&self.pool).await
     }
 
     pub async fn update_organization(
         &self,
+        scope: TenantScope,
         id: Uuid,
         data: &UpdateOrganizationRequest,
-    ) -> Result<Organization, Error> {
+    ) -> Result<Option<Organization>, Error> {
         sqlx::query_as::<_, Organization>(
-        "UPDATE organizations SET name = $1, logo_url = $2, updated_at = now() WHERE id = $3 RETURNING *",
-    )
-    .bind(&data.name)
-    .bind(&data.logo_url)
-    .bind(id)
-    .fetch_one(&self.pool)
-    .await
+            "UPDATE organizations SET name = COALESCE($1, name),
+                logo_url = COALESCE($2, logo_url), email = COALESCE($7, email),
+                geofence_policy = COALESCE($5, geofence_policy),
+                timezone = COALESCE($6, timezone), updated_at = now()
+             WHERE id = $3 AND ($4::uuid IS NULL OR id = $4)
+             RETURNING *",
+        )
+        .bind(&data.name)
+        .bind(&data.logo_url)
+        .bind(id)
+        .bind(scope.organization_id())
+        .bind(data.geofence_policy)
+        .bind(&data.timezone)
+        .bind(&data.email)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn is_known_timezone(&self, timezone: &str) -> Result<bool, Error> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS (SELECT 1 FROM pg_timezone_names WHERE name = $1)",
+        )
+        .bind(timezone)
+        .fetch_one(&self.pool)
+        .await
     }
 
-    pub async fn delete_organization(&self, id: Uuid) -> Result<(), Error> {
-        sqlx::query("DELETE FROM organizations WHERE id = $1")
+    pub async fn delete_organization(&self, scope: TenantScope, id: Uuid) -> Result<(), Error> {
+        sqlx::query("DELETE FROM organizations WHERE id = $1 AND ($2::uuid IS NULL OR id = $2)")
             .bind(id)
+            .bind(scope.organization_id())
             .execute(&self.pool)
             .await?;
         Ok(())
     }
 }
+
+fn organization_sort_key(request: &ListOrganizationsRequest) -> SortKey {
+    let (column, sql_type) = match request.sort_by {
+        OrganizationSortField::Name => ("name", "text"),
+        OrganizationSortField::CreatedAt => ("created_at", "timestamptz"),
+    };
+    SortKey {
+        column,
+        sql_type,
+        nullable: false,
+        direction: request.direction,
+    }
+}
+
+/// Cursor of `org` in a list sorted by `sort_by`, matching `organization_sort_key`.
+pub fn organization_cursor(sort_by: OrganizationSortField, org: &Organization) -> PageCursor {
+    let key = match sort_by {
+        OrganizationSortField::Name => org.name.clone(),
+        OrganizationSortField::CreatedAt => org.created_at.to_rfc3339(),
+    };
+    PageCursor {
+        key: Some(key),
+        id: org.id,
+    }
+}
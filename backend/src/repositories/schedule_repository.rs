@@ -0,0 +1,243 @@
+use crate::repositories::tenant_scope::TenantScope;
+use shared::models::{
+    group_model::Group, shift_assignment_model::ShiftAssignment, shift_model::Shift,
+};
+use sqlx::{Error, PgPool};
+use uuid::Uuid;
+
+pub struct ScheduleRepository {
+    pub pool: PgPool,
+}
+
+impl ScheduleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_shift(&self, shift: &Shift) -> Result<Shift, Error> {
+        sqlx::query_as::<_, Shift>(
+            "INSERT INTO shifts (id, organization_id, name, start_time, end_time, weekdays, grace_minutes)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING *",
+        )
+        .bind(shift.id)
+        .bind(shift.organization_id)
+        .bind(&shift.name)
+        .bind(shift.start_time)
+        .bind(shift.end_time)
+        .bind(&shift.weekdays)
+        .bind(shift.grace_minutes)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn get_shifts(&self, scope: TenantScope) -> Result<Vec<Shift>, Error> {
+        sqlx::query_as::<_, Shift>(
+            "SELECT * FROM shifts
+             WHERE ($1::uuid IS NULL OR organization_id = $1)
+             ORDER BY start_time",
+        )
+        .bind(scope.organization_id())
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_shift_by_id(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+    ) -> Result<Option<Shift>, Error> {
+        sqlx::query_as::<_, Shift>(
+            "SELECT * FROM shifts
+             WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn update_shift(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+        shift: &Shift,
+    ) -> Result<Option<Shift>, Error> {
+        sqlx::query_as::<_, Shift>(
+            "UPDATE shifts SET
+                name = $1, start_time = $2, end_time = $3,
+                weekdays = $4, grace_minutes = $5, updated_at = now()
+             WHERE id = $6 AND ($7::uuid IS NULL OR organization_id = $7)
+             RETURNING *",
+        )
+        .bind(&shift.name)
+        .bind(shift.start_time)
+        .bind(shift.end_time)
+        .bind(&shift.weekdays)
+        .bind(shift.grace_minutes)
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn delete_shift(&self, scope: TenantScope, id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "DELETE FROM shifts WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+
+    pub async fn find_shift_for_user(
+        &self,
+        user_id: Uuid,
+        organization_id: Uuid,
+        weekday: i32,
+    ) -> Result<Option<Shift>, Error> {
+        sqlx::query_as::<_, Shift>(
+            "SELECT s.* FROM shifts s
+             JOIN shift_assignments a ON a.shift_id = s.id
+             LEFT JOIN group_members gm ON gm.group_id = a.group_id
+             WHERE s.organization_id = $2
+               AND (a.user_id = $1 OR gm.user_id = $1)
+               AND $3 = ANY(s.weekdays)
+             ORDER BY (a.user_id IS NOT NULL) DESC, s.start_time
+             LIMIT 1",
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .bind(weekday)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn create_group(&self, group: &Group) -> Result<Group, Error> {
+        sqlx::query_as::<_, Group>(
+            "INSERT INTO groups (id, organization_id, name)
+             VALUES ($1, $2, $3)
+             RETURNING *",
+        )
+        .bind(group.id)
+        .bind(group.organization_id)
+        .bind(&group.name)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn get_groups(&self, scope: TenantScope) -> Result<Vec<Group>, Error> {
+        sqlx::query_as::<_, Group>(
+            "SELECT * FROM groups
+             WHERE ($1::uuid IS NULL OR organization_id = $1)
+             ORDER BY name",
+        )
+        .bind(scope.organization_id())
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_group_by_id(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+    ) -> Result<Option<Group>, Error> {
+        sqlx::query_as::<_, Group>(
+            "SELECT * FROM groups
+             WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn add_group_member(&self, group: &Group, user_id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "INSERT INTO group_members (group_id, user_id)
+             SELECT $1, id FROM users WHERE id = $2 AND organization_id = $3
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(group.id)
+        .bind(user_id)
+        .bind(group.organization_id)
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+
+    pub async fn remove_group_member(&self, group_id: Uuid, user_id: Uuid) -> Result<u64, Error> {
+        sqlx::query("DELETE FROM group_members WHERE group_id = $1 AND user_id = $2")
+            .bind(group_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await
+            .map(|result| result.rows_affected())
+    }
+
+    pub async fn get_group_member_ids(&self, group_id: Uuid) -> Result<Vec<Uuid>, Error> {
+        sqlx::query_scalar("SELECT user_id FROM group_members WHERE group_id = $1")
+            .bind(group_id)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn is_member_of_organization(
+        &self,
+        user_id: Uuid,
+        organization_id: Uuid,
+    ) -> Result<bool, Error> {
+        sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM users WHERE id = $1 AND organization_id = $2)",
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn create_assignment(
+        &self,
+        assignment: &ShiftAssignment,
+    ) -> Result<ShiftAssignment, Error> {
+        sqlx::query_as::<_, ShiftAssignment>(
+            "INSERT INTO shift_assignments (id, shift_id, user_id, group_id)
+             VALUES ($1, $2, $3, $4)
+             RETURNING *",
+        )
+        .bind(assignment.id)
+        .bind(assignment.shift_id)
+        .bind(assignment.user_id)
+        .bind(assignment.group_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn get_assignments_for_shift(
+        &self,
+        shift_id: Uuid,
+    ) -> Result<Vec<ShiftAssignment>, Error> {
+        sqlx::query_as::<_, ShiftAssignment>(
+            "SELECT * FROM shift_assignments WHERE shift_id = $1 ORDER BY created_at",
+        )
+        .bind(shift_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn delete_assignment(&self, scope: TenantScope, id: Uuid) -> Result<u64, Error> {
+        sqlx::query(
+            "DELETE FROM shift_assignments a
+             USING shifts s
+             WHERE a.id = $1 AND a.shift_id = s.id
+               AND ($2::uuid IS NULL OR s.organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+}
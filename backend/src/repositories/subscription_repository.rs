@@ -0,0 +1,73 @@
+use crate::repositories::tenant_scope::TenantScope;
+use chrono::{DateTime, Utc};
+use shared::models::subscription_model::Subscription;
+use shared::types::models::subscription::subscription_status::SubscriptionStatus;
+use sqlx::{Error, PgPool};
+use uuid::Uuid;
+
+pub struct SubscriptionRepository {
+    pub pool: PgPool,
+}
+
+impl SubscriptionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Subscriptions belong to the organization's owner.
+    pub async fn find_subscription_for_organization(
+        &self,
+        scope: TenantScope,
+        organization_id: Uuid,
+    ) -> Result<Option<Subscription>, Error> {
+        sqlx::query_as::<_, Subscription>(
+            "SELECT s.* FROM subscriptions s
+             JOIN organizations o ON o.owner_id = s.user_id
+             WHERE o.id = $1 AND ($2::uuid IS NULL OR o.id = $2)",
+        )
+        .bind(organization_id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Paid subscriptions past their expiry that are not yet expired; the only ones whose
+    /// status can move with time.
+    pub async fn find_lapsing_subscriptions(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Subscription>, Error> {
+        sqlx::query_as::<_, Subscription>(
+            "SELECT * FROM subscriptions
+             WHERE plan <> 'free'
+               AND status IN ('active', 'graceperiod')
+               AND expiry_date <= $1",
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Each change carries the status it was read with; rows changed since are left alone.
+    pub async fn update_statuses(
+        &self,
+        changes: &[(SubscriptionStatus, Subscription)],
+    ) -> Result<u64, Error> {
+        let mut tx = self.pool.begin().await?;
+        let mut updated = 0;
+
+        for (previous, subscription) in changes {
+            updated +=
+                sqlx::query("UPDATE subscriptions SET status = $2 WHERE id = $1 AND status = $3")
+                    .bind(subscription.id)
+                    .bind(subscription.status)
+                    .bind(previous)
+                    .execute(&mut *tx)
+                    .await?
+                    .rows_affected();
+        }
+
+        tx.commit().await?;
+        Ok(updated)
+    }
+}
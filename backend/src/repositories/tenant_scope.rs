@@ -0,0 +1,29 @@
+use shared::types::models::user::role::Role;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TenantScope {
+    AllOrganizations,
+    Organization(Uuid),
+}
+
+impl TenantScope {
+    pub fn for_user(role: Role, organization_id: Uuid) -> Self {
+        match role {
+            Role::Superadmin => Self::AllOrganizations,
+            _ => Self::Organization(organization_id),
+        }
+    }
+
+    pub fn organization_id(&self) -> Option<Uuid> {
+        match self {
+            Self::AllOrganizations => None,
+            Self::Organization(id) => Some(*id),
+        }
+    }
+
+    pub fn allows(&self, organization_id: Uuid) -> bool {
+        self.organization_id()
+            .is_none_or(|scoped| scoped == organization_id)
+    }
+}
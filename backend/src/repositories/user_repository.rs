@@ -1,6 +1,20 @@
+use crate::repositories::{
+    listing::{SortKey, bind_page, contains_pattern},
+    tenant_scope::TenantScope,
+};
 use shared::models::user_model::User;
-use shared::types::requests::user::update_user_request::UpdateUserRequest;
+use shared::types::models::user::{role::Role, user_sort_field::UserSortField};
+use shared::types::requests::page_request::{PageCursor, PageRequest};
+use shared::types::requests::user::{
+    list_users_request::ListUsersRequest, update_user_request::UpdateUserRequest,
+};
 use sqlx::{Error, PgPool};
+use uuid::Uuid;
+
+const USER_LIST_FILTER: &str = "WHERE ($1::uuid IS NULL OR organization_id = $1)
+       AND ($2::role IS NULL OR role = $2)
+       AND ($3::user_status IS NULL OR status = $3)
+       AND ($4::text IS NULL OR name ILIKE $4 OR email ILIKE $4)";
 
 pub struct UserRepository {
     pub pool: PgPool,
@@ -13,29 +27,150 @@ impl UserRepository {
 
     pub async fn register_user(&self, user: &User) -> Result<User, Error> {
         sqlx::query_as::<_, User>(
-            "INSERT INTO users (id, name, email) VALUES ($1, $2, $3) RETURNING *",
+            "INSERT INTO users (id, name, email, organization_id, role, status, password_hash)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING *",
         )
         .bind(user.id)
         .bind(&user.name)
         .bind(&user.email)
+        .bind(user.organization_id)
+        .bind(user.role)
+        .bind(user.status)
+        .bind(&user.password_hash)
         .fetch_one(&self.pool)
         .await
     }
 
-    pub async fn find_user(&self, email: &str) -> Result<Option<User>, Error> {
-        sqlx::query_as::<_, User>("SELECT * FROM users WHERE email = $1")
-            .bind(email)
-            .fetch_optional(&self.pool)
-            .await
+    /// Inserts every user or none of them.
+    pub async fn register_users(&self, users: &[User]) -> Result<Vec<User>, Error> {
+        let mut tx = self.pool.begin().await?;
+        let mut saved = Vec::with_capacity(users.len());
+
+        for user in users {
+            let inserted = sqlx::query_as::<_, User>(
+                "INSERT INTO users (id, name, email, organization_id, role, status, password_hash)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7)
+                 RETURNING *",
+            )
+            .bind(user.id)
+            .bind(&user.name)
+            .bind(&user.email)
+            .bind(user.organization_id)
+            .bind(user.role)
+            .bind(user.status)
+            .bind(&user.password_hash)
+            .fetch_one(&mut *tx)
+            .await?;
+            saved.push(inserted);
+        }
+
+        tx.commit().await?;
+        Ok(saved)
     }
 
-    pub async fn get_all_users(&self) -> Result<Vec<User>, Error> {
-        sqlx::query_as::<_, User>("SELECT * FROM users")
+    /// Which of `emails` are already registered in any organization, compared case-insensitively.
+    pub async fn find_registered_emails(&self, emails: &[String]) -> Result<Vec<String>, Error> {
+        sqlx::query_scalar::<_, String>(
+            "SELECT lower(email) FROM users WHERE lower(email) = ANY($1)",
+        )
+        .bind(emails)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Emails are matched case-insensitively, like the duplicate check on registration.
+    pub async fn find_user(&self, scope: TenantScope, email: &str) -> Result<Option<User>, Error> {
+        sqlx::query_as::<_, User>(
+            "SELECT * FROM users
+             WHERE lower(email) = lower($1) AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(email)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn find_user_by_id(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+    ) -> Result<Option<User>, Error> {
+        sqlx::query_as::<_, User>(
+            "SELECT * FROM users
+             WHERE id = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn get_users_by_organization(&self, org_id: Uuid) -> Result<Vec<User>, Error> {
+        sqlx::query_as::<_, User>("SELECT * FROM users WHERE organization_id = $1")
+            .bind(org_id)
             .fetch_all(&self.pool)
             .await
     }
 
-    pub async fn update_user(&self, email: &str, update: UpdateUserRequest) -> Result<User, Error> {
+    pub async fn update_role(
+        &self,
+        scope: TenantScope,
+        id: Uuid,
+        role: Role,
+    ) -> Result<Option<User>, Error> {
+        sqlx::query_as::<_, User>(
+            "UPDATE users SET role = $1, updated_at = now()
+             WHERE id = $2 AND ($3::uuid IS NULL OR organization_id = $3)
+             RETURNING *",
+        )
+        .bind(role)
+        .bind(id)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn count_users(
+        &self,
+        scope: TenantScope,
+        request: &ListUsersRequest,
+    ) -> Result<i64, Error> {
+        sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM users {USER_LIST_FILTER}"))
+            .bind(scope.organization_id())
+            .bind(request.role)
+            .bind(request.status)
+            .bind(contains_pattern(request.search.as_deref()))
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn list_users(
+        &self,
+        scope: TenantScope,
+        request: &ListUsersRequest,
+        page: &PageRequest,
+    ) -> Result<Vec<User>, Error> {
+        let query = format!(
+            "SELECT * FROM users {USER_LIST_FILTER}{}",
+            user_sort_key(request).page_clause(page, 5)
+        );
+
+        let query = sqlx::query_as::<_, User>(&query)
+            .bind(scope.organization_id())
+            .bind(request.role)
+            .bind(request.status)
+            .bind(contains_pattern(request.search.as_deref()));
+
+        bind_page(query, page).fetch_all(&self.pool).await
+    }
+
+    pub async fn update_user(
+        &self,
+        scope: TenantScope,
+        email: &str,
+        update: UpdateUserRequest,
+    ) -> Result<Option<User>, Error> {
         sqlx::query_as::<_, User>(
             r#"
             UPDATE users
@@ -43,22 +178,53 @@ impl UserRepository {
                 name = COALESCE($1, name),
                 email = COALESCE($2, email),
                 updated_at = now()
-            WHERE email = $3
-            RETURNING id, name, email, role, status, created_at, updated_at
+            WHERE email = $3 AND ($4::uuid IS NULL OR organization_id = $4)
+            RETURNING *
             "#,
         )
         .bind(update.name)
         .bind(update.email)
         .bind(email)
-        .fetch_one(&self.pool)
+        .bind(scope.organization_id())
+        .fetch_optional(&self.pool)
         .await
     }
 
-    pub async fn delete_user(&self, email: &str) -> Result<(), Error> {
-        sqlx::query("DELETE FROM users WHERE email = $1")
-            .bind(email)
-            .execute(&self.pool)
-            .await
-            .map(|_| ())
+    pub async fn delete_user(&self, scope: TenantScope, email: &str) -> Result<u64, Error> {
+        sqlx::query(
+            "DELETE FROM users WHERE email = $1 AND ($2::uuid IS NULL OR organization_id = $2)",
+        )
+        .bind(email)
+        .bind(scope.organization_id())
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+    }
+}
+
+fn user_sort_key(request: &ListUsersRequest) -> SortKey {
+    let (column, sql_type) = match request.sort_by {
+        UserSortField::Name => ("name", "text"),
+        UserSortField::Email => ("email", "text"),
+        UserSortField::CreatedAt => ("created_at", "timestamptz"),
+    };
+    SortKey {
+        column,
+        sql_type,
+        nullable: false,
+        direction: request.direction,
+    }
+}
+
+/// Cursor of `user` in a list sorted by `sort_by`, matching `user_sort_key`.
+pub fn user_cursor(sort_by: UserSortField, user: &User) -> PageCursor {
+    let key = match sort_by {
+        UserSortField::Name => user.name.clone(),
+        UserSortField::Email => user.email.clone(),
+        UserSortField::CreatedAt => user.created_at.to_rfc3339(),
+    };
+    PageCursor {
+        key: Some(key),
+        id: user.id,
     }
 }
@@ -1,31 +1,81 @@
 use crate::{
     repositories::app_repository::AppRepository,
     services::{
-        attendance_service::AttendanceService, organization_service::OrganizationService,
-        user_service::UserService,
+        attendance_service::AttendanceService, auth_service::AuthService,
+        correction_service::CorrectionService, device_service::DeviceService,
+        export_service::ExportService, geofence_service::GeofenceService,
+        holiday_service::HolidayService, leave_service::LeaveService,
+        organization_service::OrganizationService, schedule_service::ScheduleService,
+        subscription_service::SubscriptionService, user_service::UserService,
     },
 };
 use std::sync::Arc;
 
 pub struct AppService {
+    pub auth_service: Arc<AuthService>,
     pub user_service: Arc<UserService>,
     pub organization_service: Arc<OrganizationService>,
     pub attendance_service: Arc<AttendanceService>,
+    pub schedule_service: Arc<ScheduleService>,
+    pub geofence_service: Arc<GeofenceService>,
+    pub device_service: Arc<DeviceService>,
+    pub leave_service: Arc<LeaveService>,
+    pub correction_service: Arc<CorrectionService>,
+    pub holiday_service: Arc<HolidayService>,
+    pub export_service: Arc<ExportService>,
+    pub subscription_service: Arc<SubscriptionService>,
 }
 
 impl AppService {
     pub async fn new(repo: Arc<AppRepository>) -> Self {
+        let auth_service = Arc::new(AuthService::new(
+            repo.auth_repository.clone(),
+            repo.user_repository.clone(),
+        ));
         let user_service = Arc::new(UserService::new(repo.user_repository.clone()));
         let organization_service = Arc::new(OrganizationService::new(
             repo.organization_repository.clone(),
         ));
-        let attendance_service =
-            Arc::new(AttendanceService::new(repo.attendance_repository.clone()));
+        let attendance_service = Arc::new(AttendanceService::new(
+            repo.attendance_repository.clone(),
+            repo.schedule_repository.clone(),
+            repo.geofence_repository.clone(),
+            repo.holiday_repository.clone(),
+        ));
+        let schedule_service = Arc::new(ScheduleService::new(repo.schedule_repository.clone()));
+        let geofence_service = Arc::new(GeofenceService::new(repo.geofence_repository.clone()));
+        let device_service = Arc::new(DeviceService::new(
+            repo.device_repository.clone(),
+            attendance_service.clone(),
+        ));
+        let leave_service = Arc::new(LeaveService::new(
+            repo.leave_repository.clone(),
+            attendance_service.clone(),
+        ));
+        let correction_service = Arc::new(CorrectionService::new(
+            repo.correction_repository.clone(),
+            repo.attendance_repository.clone(),
+            attendance_service.clone(),
+        ));
+        let holiday_service = Arc::new(HolidayService::new(repo.holiday_repository.clone()));
+        let export_service = Arc::new(ExportService::new(repo.attendance_repository.clone()));
+        let subscription_service = Arc::new(SubscriptionService::new(
+            repo.subscription_repository.clone(),
+        ));
 
         Self {
+            auth_service,
             user_service,
             organization_service,
             attendance_service,
+            schedule_service,
+            geofence_service,
+            device_service,
+            leave_service,
+            correction_service,
+            holiday_service,
+            export_service,
+            subscription_service,
         }
     }
 }
@@ -1,19 +1,47 @@
-use crate::repositories::attendance_repository::AttendanceRepository;
-use chrono::Utc;
+use crate::graphql::error::AppError;
+use crate::repositories::{
+    attendance_repository::{AttendanceRepository, ClockIn, attendance_cursor},
+    geofence_repository::GeofenceRepository,
+    holiday_repository::HolidayRepository,
+    schedule_repository::ScheduleRepository,
+    tenant_scope::TenantScope,
+};
+use crate::utils::qr_utils::{QR_ROTATION_SECONDS, QrPayload};
+use async_graphql::Enum;
+use chrono::{DateTime, Datelike, NaiveDateTime, NaiveTime, Utc};
 use shared::{
     models::attendance_model::Attendance,
     prelude::MessageLookup,
     types::{
-        requests::attendance::{
-            register_attendance_request::RegisterAttendanceRequest,
-            update_attendance_request::UpdateAttendanceRequest,
+        models::attendance::{
+            attendance_method::AttendanceMethod, attendance_status::AttendanceStatus,
+            attendance_type::AttendanceType, geofence_policy::GeofencePolicy,
+            geolocation::GeoLocation,
+        },
+        requests::{
+            attendance::{
+                attendance_summary_request::AttendanceSummaryRequest, clock_request::ClockRequest,
+                list_attendances_request::ListAttendancesRequest,
+                qr_check_in_request::QrCheckInRequest,
+                register_attendance_request::RegisterAttendanceRequest,
+            },
+            page_request::PageRequest,
+        },
+        responses::{
+            attendance_response::AttendanceResponse,
+            attendance_summary_response::AttendanceSummaryResponse, page_response::PageResponse,
+            qr_code_response::QrCodeResponse,
         },
-        responses::attendance_response::AttendanceResponse,
     },
     utils::locale_utils::Namespace,
 };
 use std::{fmt, sync::Arc};
+use tokio::sync::broadcast;
 use uuid::Uuid;
+use validator::Validate;
+
+const ATTENDANCE_EVENT_CAPACITY: usize = 256;
+const MAX_REPORT_DAYS: i64 = 366;
 
 #[derive(Debug)]
 pub enum AttendanceServiceError {
@@ -21,6 +49,21 @@ pub enum AttendanceServiceError {
     DuplicateAttendance,
     DbError(String),
     InvalidId(String),
+    Forbidden,
+    AlreadyClockedIn,
+    NotClockedIn,
+    InvalidLocation,
+    UnsupportedMethod,
+    LocationRequired,
+    OutsideGeofence(f64),
+    InvalidQrCode,
+    ExpiredQrCode,
+    QrCodeUsed,
+    InvalidReportRange,
+    ReportForbidden,
+    ListForbidden,
+    ViewForbidden,
+    UserNotInOrganization,
 }
 
 impl AttendanceServiceError {
@@ -38,6 +81,51 @@ impl AttendanceServiceError {
             AttendanceServiceError::InvalidId(_) => {
                 messages.get_message(Namespace::Attendance, "invalid_id")
             }
+            AttendanceServiceError::Forbidden => {
+                messages.get_message(Namespace::Attendance, "forbidden")
+            }
+            AttendanceServiceError::AlreadyClockedIn => {
+                messages.get_message(Namespace::Attendance, "already_checked_in")
+            }
+            AttendanceServiceError::NotClockedIn => {
+                messages.get_message(Namespace::Attendance, "not_checked_in_yet")
+            }
+            AttendanceServiceError::InvalidLocation => {
+                messages.get_message(Namespace::Attendance, "location.invalid")
+            }
+            AttendanceServiceError::UnsupportedMethod => {
+                messages.get_message(Namespace::Attendance, "location.unsupported_method")
+            }
+            AttendanceServiceError::LocationRequired => {
+                messages.get_message(Namespace::Attendance, "location.required")
+            }
+            AttendanceServiceError::OutsideGeofence(_) => {
+                messages.get_message(Namespace::Attendance, "location.outside_geofence")
+            }
+            AttendanceServiceError::InvalidQrCode => {
+                messages.get_message(Namespace::Attendance, "qr.invalid")
+            }
+            AttendanceServiceError::ExpiredQrCode => {
+                messages.get_message(Namespace::Attendance, "qr.expired")
+            }
+            AttendanceServiceError::QrCodeUsed => {
+                messages.get_message(Namespace::Attendance, "qr.used")
+            }
+            AttendanceServiceError::InvalidReportRange => {
+                messages.get_message(Namespace::Attendance, "report.invalid_range")
+            }
+            AttendanceServiceError::ReportForbidden => {
+                messages.get_message(Namespace::Attendance, "report.forbidden")
+            }
+            AttendanceServiceError::ListForbidden => {
+                messages.get_message(Namespace::Attendance, "list.forbidden")
+            }
+            AttendanceServiceError::ViewForbidden => {
+                messages.get_message(Namespace::Attendance, "fetch.forbidden")
+            }
+            AttendanceServiceError::UserNotInOrganization => {
+                messages.get_message(Namespace::Attendance, "create.user_not_in_organization")
+            }
         }
     }
 }
@@ -49,23 +137,231 @@ impl fmt::Display for AttendanceServiceError {
             AttendanceServiceError::DuplicateAttendance => write!(f, "Duplicate attendance entry"),
             AttendanceServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
             AttendanceServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            AttendanceServiceError::Forbidden => {
+                write!(f, "Attendance belongs to another organization")
+            }
+            AttendanceServiceError::AlreadyClockedIn => write!(f, "Already clocked in today"),
+            AttendanceServiceError::NotClockedIn => write!(f, "No open clock-in to close"),
+            AttendanceServiceError::InvalidLocation => {
+                write!(f, "GPS attendance needs a valid latitude and longitude")
+            }
+            AttendanceServiceError::UnsupportedMethod => {
+                write!(f, "Clock-in only accepts the manual and GPS methods")
+            }
+            AttendanceServiceError::LocationRequired => {
+                write!(f, "This organization requires GPS clock-ins")
+            }
+            AttendanceServiceError::OutsideGeofence(distance) => {
+                write!(
+                    f,
+                    "Location is {:.0} m outside the nearest geofence",
+                    distance
+                )
+            }
+            AttendanceServiceError::InvalidQrCode => write!(f, "QR code is not valid"),
+            AttendanceServiceError::ExpiredQrCode => write!(f, "QR code has expired"),
+            AttendanceServiceError::QrCodeUsed => write!(f, "QR code has already been used"),
+            AttendanceServiceError::InvalidReportRange => write!(
+                f,
+                "Report range must end on or after its start and span at most {MAX_REPORT_DAYS} days"
+            ),
+            AttendanceServiceError::ReportForbidden => {
+                write!(f, "Only managers can view other members' summaries")
+            }
+            AttendanceServiceError::ListForbidden => {
+                write!(f, "Only managers can list other members' attendance")
+            }
+            AttendanceServiceError::ViewForbidden => {
+                write!(f, "Only managers can view other members' attendance")
+            }
+            AttendanceServiceError::UserNotInOrganization => {
+                write!(f, "User does not belong to the organization")
+            }
         }
     }
 }
 
+impl From<AttendanceServiceError> for AppError {
+    fn from(err: AttendanceServiceError) -> Self {
+        match err {
+            AttendanceServiceError::NotFound => AppError::NotFound(err.to_string()),
+            AttendanceServiceError::DuplicateAttendance => AppError::Conflict(err.to_string()),
+            AttendanceServiceError::InvalidId(_) => AppError::Validation(err.to_string()),
+            AttendanceServiceError::DbError(_) => AppError::Internal(err.to_string()),
+            AttendanceServiceError::Forbidden => AppError::Forbidden(err.to_string()),
+            AttendanceServiceError::AlreadyClockedIn | AttendanceServiceError::NotClockedIn => {
+                AppError::Conflict(err.to_string())
+            }
+            AttendanceServiceError::InvalidLocation
+            | AttendanceServiceError::UnsupportedMethod
+            | AttendanceServiceError::InvalidQrCode
+            | AttendanceServiceError::ExpiredQrCode
+            | AttendanceServiceError::InvalidReportRange
+            | AttendanceServiceError::UserNotInOrganization => {
+                AppError::Validation(err.to_string())
+            }
+            AttendanceServiceError::QrCodeUsed => AppError::Conflict(err.to_string()),
+            AttendanceServiceError::OutsideGeofence(_)
+            | AttendanceServiceError::LocationRequired
+            | AttendanceServiceError::ReportForbidden
+            | AttendanceServiceError::ListForbidden
+            | AttendanceServiceError::ViewForbidden => AppError::Forbidden(err.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum AttendanceEventKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+#[derive(Debug, Clone)]
+pub struct AttendanceEvent {
+    pub kind: AttendanceEventKind,
+    pub attendance: AttendanceResponse,
+}
+
 pub struct AttendanceService {
     pub attendance_repository: Arc<AttendanceRepository>,
+    schedule_repository: Arc<ScheduleRepository>,
+    geofence_repository: Arc<GeofenceRepository>,
+    holiday_repository: Arc<HolidayRepository>,
+    events: broadcast::Sender<AttendanceEvent>,
 }
 
 impl AttendanceService {
-    pub fn new(attendance_repository: Arc<AttendanceRepository>) -> Self {
+    pub fn new(
+        attendance_repository: Arc<AttendanceRepository>,
+        schedule_repository: Arc<ScheduleRepository>,
+        geofence_repository: Arc<GeofenceRepository>,
+        holiday_repository: Arc<HolidayRepository>,
+    ) -> Self {
+        let (events, _) = broadcast::channel(ATTENDANCE_EVENT_CAPACITY);
+
         Self {
             attendance_repository,
+            schedule_repository,
+            geofence_repository,
+            holiday_repository,
+            events,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<AttendanceEvent> {
+        self.events.subscribe()
+    }
+
+    /// Converts an instant to the organization's wall-clock time, falling back to UTC.
+    async fn local_datetime(
+        &self,
+        organization_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<NaiveDateTime, AttendanceServiceError> {
+        let local = self
+            .attendance_repository
+            .local_datetime(organization_id, at)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        Ok(local.unwrap_or_else(|| at.naive_utc()))
+    }
+
+    async fn derive_status(
+        &self,
+        user_id: Uuid,
+        organization_id: Uuid,
+        local_clock_in: NaiveDateTime,
+    ) -> Result<AttendanceStatus, AttendanceServiceError> {
+        let is_holiday = self
+            .holiday_repository
+            .is_holiday(organization_id, local_clock_in.date())
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+        if is_holiday {
+            return Ok(AttendanceStatus::Present);
+        }
+
+        let weekday = local_clock_in.weekday().number_from_monday() as i32;
+
+        let shift = self
+            .schedule_repository
+            .find_shift_for_user(user_id, organization_id, weekday)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        Ok(shift.map_or(AttendanceStatus::Present, |shift| {
+            shift.status_for(local_clock_in.time())
+        }))
+    }
+
+    /// Distance to the nearest fence and whether the clock-in lies outside all of them. Once an
+    /// organization has fences, clock-ins without a location are rejected or flagged per policy.
+    async fn check_geofence(
+        &self,
+        organization_id: Uuid,
+        method: AttendanceMethod,
+        request: &ClockRequest,
+    ) -> Result<(Option<f64>, bool), AttendanceServiceError> {
+        let location = match (method, request.lat, request.long) {
+            (AttendanceMethod::Gps, Some(lat), Some(long)) => {
+                let location = GeoLocation { lat, long };
+                location
+                    .validate()
+                    .map_err(|_| AttendanceServiceError::InvalidLocation)?;
+                Some(location)
+            }
+            (AttendanceMethod::Gps, _, _) => return Err(AttendanceServiceError::InvalidLocation),
+            _ => None,
+        };
+
+        let fences = self
+            .geofence_repository
+            .get_geofences(TenantScope::Organization(organization_id))
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        if fences.is_empty() {
+            return Ok((None, false));
+        }
+
+        let distance = location.map(|location| {
+            fences
+                .iter()
+                .map(|fence| fence.distance_from(&location))
+                .fold(f64::INFINITY, f64::min)
+        });
+
+        let outside = distance.is_none_or(|distance| distance > 0.0);
+        if outside {
+            let policy = self
+                .geofence_repository
+                .get_geofence_policy(organization_id)
+                .await
+                .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+            if policy == GeofencePolicy::Reject {
+                return Err(distance.map_or(
+                    AttendanceServiceError::LocationRequired,
+                    AttendanceServiceError::OutsideGeofence,
+                ));
+            }
         }
+
+        Ok((distance, outside))
+    }
+
+    pub(crate) fn publish(&self, kind: AttendanceEventKind, attendance: &AttendanceResponse) {
+        let _ = self.events.send(AttendanceEvent {
+            kind,
+            attendance: attendance.clone(),
+        });
     }
 
     pub async fn create_attendance(
         &self,
+        scope: TenantScope,
         request: RegisterAttendanceRequest,
     ) -> Result<AttendanceResponse, AttendanceServiceError> {
         let user_id = Uuid::parse_str(&request.user_id)
@@ -73,14 +369,42 @@ impl AttendanceService {
         let organization_id = Uuid::parse_str(&request.organization_id)
             .map_err(|_| AttendanceServiceError::InvalidId("organization_id".into()))?;
 
+        if !scope.allows(organization_id) {
+            return Err(AttendanceServiceError::Forbidden);
+        }
+        let is_member = self
+            .attendance_repository
+            .is_member_of(user_id, organization_id)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+        if !is_member {
+            return Err(AttendanceServiceError::UserNotInOrganization);
+        }
+
+        let status = match (request.status, request.clock_in) {
+            (Some(status), _) => status,
+            (None, Some(clock_in)) => {
+                let local_clock_in = self.local_datetime(organization_id, clock_in).await?;
+                self.derive_status(user_id, organization_id, local_clock_in)
+                    .await?
+            }
+            (None, None) => AttendanceStatus::default(),
+        };
+
+        let local_date = self
+            .local_datetime(organization_id, request.date)
+            .await?
+            .date();
+
         let attendance = Attendance {
             user_id,
             organization_id,
             date: request.date,
+            local_date,
             clock_in: request.clock_in,
             clock_out: request.clock_out,
             method: request.method.unwrap_or_else(Default::default),
-            status: request.status.unwrap_or_else(Default::default),
+            status,
             attendance_type: request.attendance_type.unwrap_or_else(Default::default),
             lat: request.lat,
             long: request.long,
@@ -93,82 +417,534 @@ impl AttendanceService {
             .await
             .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
 
-        Ok(AttendanceResponse::from(created))
+        let created = AttendanceResponse::from(created);
+        self.publish(AttendanceEventKind::Created, &created);
+
+        Ok(created)
     }
 
+    pub async fn clock_in(
+        &self,
+        user_id: &str,
+        organization_id: &str,
+        request: ClockRequest,
+    ) -> Result<AttendanceResponse, AttendanceServiceError> {
+        let (user_id, organization_id) = parse_member_ids(user_id, organization_id)?;
+
+        // QR and badge methods have their own endpoints that verify them.
+        let method = request.method.unwrap_or_default();
+        if !matches!(method, AttendanceMethod::Manual | AttendanceMethod::Gps) {
+            return Err(AttendanceServiceError::UnsupportedMethod);
+        }
+
+        let (distance_meters, outside_geofence) = self
+            .check_geofence(organization_id, method, &request)
+            .await?;
+
+        self.open_attendance(
+            Attendance {
+                user_id,
+                organization_id,
+                method,
+                lat: request.lat,
+                long: request.long,
+                distance_meters,
+                outside_geofence,
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+    }
+
+    pub async fn issue_qr_code(
+        &self,
+        scope: TenantScope,
+        organization_id: &str,
+        geofence_id: Option<&str>,
+    ) -> Result<QrCodeResponse, AttendanceServiceError> {
+        let organization_id = Uuid::parse_str(organization_id)
+            .map_err(|_| AttendanceServiceError::InvalidId("organization_id".into()))?;
+
+        let geofence_id = match geofence_id {
+            Some(id) => {
+                let id = Uuid::parse_str(id)
+                    .map_err(|_| AttendanceServiceError::InvalidId("geofence_id".into()))?;
+                self.geofence_repository
+                    .find_geofence_by_id(scope, id)
+                    .await
+                    .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?
+                    .ok_or(AttendanceServiceError::Forbidden)?;
+                Some(id)
+            }
+            None => None,
+        };
+
+        let payload = QrPayload::current(organization_id, geofence_id, Utc::now());
+
+        Ok(QrCodeResponse {
+            code: payload.sign(),
+            expires_at: payload.expires_at(),
+            rotation_seconds: QR_ROTATION_SECONDS,
+        })
+    }
+
+    pub async fn check_in_with_qr(
+        &self,
+        user_id: &str,
+        organization_id: &str,
+        request: QrCheckInRequest,
+    ) -> Result<AttendanceResponse, AttendanceServiceError> {
+        let (user_id, organization_id) = parse_member_ids(user_id, organization_id)?;
+        let payload =
+            QrPayload::verify(&request.code).ok_or(AttendanceServiceError::InvalidQrCode)?;
+
+        if payload.organization_id != organization_id {
+            return Err(AttendanceServiceError::Forbidden);
+        }
+        if payload.is_expired(Utc::now()) {
+            return Err(AttendanceServiceError::ExpiredQrCode);
+        }
+
+        let distance_meters = match payload.geofence_id {
+            Some(geofence_id) => {
+                let location = match (request.lat, request.long) {
+                    (Some(lat), Some(long)) => GeoLocation { lat, long },
+                    _ => return Err(AttendanceServiceError::InvalidLocation),
+                };
+                let fence = self
+                    .geofence_repository
+                    .find_geofence_by_id(TenantScope::Organization(organization_id), geofence_id)
+                    .await
+                    .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?
+                    .ok_or(AttendanceServiceError::InvalidQrCode)?;
+
+                let distance = fence.distance_from(&location);
+                if distance > 0.0 {
+                    return Err(AttendanceServiceError::OutsideGeofence(distance));
+                }
+                Some(distance)
+            }
+            None => None,
+        };
+
+        self.open_attendance(
+            Attendance {
+                user_id,
+                organization_id,
+                method: AttendanceMethod::Qrcode,
+                lat: request.lat,
+                long: request.long,
+                distance_meters,
+                ..Default::default()
+            },
+            Some(&request.code),
+        )
+        .await
+    }
+
+    /// Badge taps alternate: the first tap clocks in, the next one clocks out.
+    pub async fn record_tap(
+        &self,
+        user_id: Uuid,
+        organization_id: Uuid,
+        method: AttendanceMethod,
+    ) -> Result<AttendanceResponse, AttendanceServiceError> {
+        let today = self
+            .local_datetime(organization_id, Utc::now())
+            .await?
+            .date();
+        let open = self
+            .attendance_repository
+            .find_open_attendance(user_id, organization_id, today)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        if open.is_some() {
+            return self
+                .clock_out(&user_id.to_string(), &organization_id.to_string())
+                .await;
+        }
+
+        self.open_attendance(
+            Attendance {
+                user_id,
+                organization_id,
+                method,
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+    }
+
+    /// Records today's clock-in, filling a pre-created row (e.g. a materialized absence) if any.
+    /// A QR code is only redeemed when the clock-in is saved.
+    async fn open_attendance(
+        &self,
+        attendance: Attendance,
+        qr_code: Option<&str>,
+    ) -> Result<AttendanceResponse, AttendanceServiceError> {
+        let (user_id, organization_id) = (attendance.user_id, attendance.organization_id);
+        let now = Utc::now();
+        let local_now = self.local_datetime(organization_id, now).await?;
+        let local_date = local_now.date();
+
+        let today = self
+            .attendance_repository
+            .find_attendance_for_day(user_id, organization_id, local_date)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        let attendance = Attendance {
+            date: now - (local_now - local_date.and_time(NaiveTime::MIN)),
+            local_date,
+            clock_in: Some(now),
+            status: self
+                .derive_status(user_id, organization_id, local_now)
+                .await?,
+            attendance_type: AttendanceType::DoubleMark,
+            ..attendance
+        };
+
+        let existing = match today {
+            Some(existing) if existing.clock_in.is_some() => {
+                return Err(AttendanceServiceError::AlreadyClockedIn);
+            }
+            Some(existing) => Some(existing.id),
+            None => None,
+        };
+
+        let saved = self
+            .attendance_repository
+            .clock_in(existing, &attendance, qr_code)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        let (kind, saved) = match saved {
+            ClockIn::Created(created) => (AttendanceEventKind::Created, *created),
+            ClockIn::Opened(opened) => (AttendanceEventKind::Updated, *opened),
+            ClockIn::AlreadyClockedIn => return Err(AttendanceServiceError::AlreadyClockedIn),
+            ClockIn::QrCodeUsed => return Err(AttendanceServiceError::QrCodeUsed),
+        };
+
+        let saved = AttendanceResponse::from(saved);
+        self.publish(kind, &saved);
+
+        Ok(saved)
+    }
+
+    pub async fn clock_out(
+        &self,
+        user_id: &str,
+        organization_id: &str,
+    ) -> Result<AttendanceResponse, AttendanceServiceError> {
+        let (user_id, organization_id) = parse_member_ids(user_id, organization_id)?;
+        let today = self
+            .local_datetime(organization_id, Utc::now())
+            .await?
+            .date();
+
+        let open = self
+            .attendance_repository
+            .find_open_attendance(user_id, organization_id, today)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?
+            .ok_or(AttendanceServiceError::NotClockedIn)?;
+
+        let closed = self
+            .attendance_repository
+            .close_clock_out(open.id, Utc::now())
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?
+            .ok_or(AttendanceServiceError::NotClockedIn)?;
+
+        let closed = AttendanceResponse::from(closed);
+        self.publish(AttendanceEventKind::Updated, &closed);
+
+        Ok(closed)
+    }
+
+    /// Drops redemptions of QR codes that have expired, which can no longer be replayed.
+    pub async fn prune_qr_redemptions(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<u64, AttendanceServiceError> {
+        self.attendance_repository
+            .prune_qr_redemptions(QrPayload::redemption_cutoff(now))
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))
+    }
+
+    pub async fn materialize_absences(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<AttendanceResponse>, AttendanceServiceError> {
+        let created = self
+            .attendance_repository
+            .materialize_absences(now)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        let created: Vec<AttendanceResponse> =
+            created.into_iter().map(AttendanceResponse::from).collect();
+        for attendance in &created {
+            self.publish(AttendanceEventKind::Created, attendance);
+        }
+
+        Ok(created)
+    }
+
+    /// Publishes the rows excused by an approved leave.
+    pub fn publish_excused(&self, excused: Vec<Attendance>) -> Vec<AttendanceResponse> {
+        let excused: Vec<AttendanceResponse> =
+            excused.into_iter().map(AttendanceResponse::from).collect();
+        for attendance in &excused {
+            let kind = if attendance.created_at == attendance.updated_at {
+                AttendanceEventKind::Created
+            } else {
+                AttendanceEventKind::Updated
+            };
+            self.publish(kind, attendance);
+        }
+
+        excused
+    }
+
+    /// Without `may_view_others` a record that belongs to someone else is refused.
     pub async fn get_attendance_by_id(
         &self,
+        scope: TenantScope,
         id: &str,
+        requested_by: &str,
+        may_view_others: bool,
     ) -> Result<Option<AttendanceResponse>, AttendanceServiceError> {
         let uuid =
             Uuid::parse_str(id).map_err(|e| AttendanceServiceError::InvalidId(e.to_string()))?;
 
         let attendance = self
             .attendance_repository
-            .get_attendance_by_id(uuid)
+            .get_attendance_by_id(scope, uuid)
             .await
             .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
 
+        let foreign = attendance
+            .as_ref()
+            .is_some_and(|a| a.user_id.to_string() != requested_by);
+        if foreign && !may_view_others {
+            return Err(AttendanceServiceError::ViewForbidden);
+        }
+
         Ok(attendance.map(AttendanceResponse::from))
     }
 
     pub async fn get_all_attendances(
         &self,
+        scope: TenantScope,
     ) -> Result<Vec<AttendanceResponse>, AttendanceServiceError> {
         let result = self
             .attendance_repository
-            .get_all_attendances()
+            .get_all_attendances(scope)
             .await
             .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
 
         Ok(result.into_iter().map(AttendanceResponse::from).collect())
     }
 
-    pub async fn update_attendance(
+    /// Members may only list their own records; without a `user_id` filter they get exactly
+    /// those.
+    pub async fn list_attendances(
         &self,
-        id: &str,
-        req: UpdateAttendanceRequest,
-    ) -> Result<AttendanceResponse, AttendanceServiceError> {
-        let uuid =
-            Uuid::parse_str(id).map_err(|e| AttendanceServiceError::InvalidId(e.to_string()))?;
+        scope: TenantScope,
+        requested_by: &str,
+        may_view_others: bool,
+        request: ListAttendancesRequest,
+        page: PageRequest,
+    ) -> Result<PageResponse<AttendanceResponse>, AttendanceServiceError> {
+        let user_id = match (request.user_id.as_deref(), may_view_others) {
+            (Some(id), false) if id != requested_by => {
+                return Err(AttendanceServiceError::ListForbidden);
+            }
+            (Some(id), _) => Some(id),
+            (None, true) => None,
+            (None, false) => Some(requested_by),
+        };
+        let user_uuid = user_id
+            .map(Uuid::parse_str)
+            .transpose()
+            .map_err(|_| AttendanceServiceError::InvalidId("user_id".into()))?;
+        let org_uuid = request
+            .organization_id
+            .as_deref()
+            .map(Uuid::parse_str)
+            .transpose()
+            .map_err(|_| AttendanceServiceError::InvalidId("organization_id".into()))?;
 
-        let mut existing = self
+        let total = if page.count_total {
+            let total = self
+                .attendance_repository
+                .count_attendances(scope, org_uuid, user_uuid, &request)
+                .await
+                .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+            Some(total as usize)
+        } else {
+            None
+        };
+
+        let rows = self
             .attendance_repository
-            .get_attendance_by_id(uuid)
+            .list_attendances(scope, org_uuid, user_uuid, &request, &page)
             .await
-            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?
-            .ok_or(AttendanceServiceError::NotFound)?;
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        Ok(page
+            .into_page(rows, |a| attendance_cursor(request.sort_by, a), total)
+            .map(AttendanceResponse::from))
+    }
+
+    pub async fn get_attendances_for_org(
+        &self,
+        scope: TenantScope,
+        org_id: &str,
+    ) -> Result<Vec<AttendanceResponse>, AttendanceServiceError> {
+        let org_uuid = Uuid::parse_str(org_id)
+            .map_err(|_| AttendanceServiceError::InvalidId("organization_id".into()))?;
+
+        let result = self
+            .attendance_repository
+            .get_all_attendances_for_org(scope, org_uuid)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        Ok(result.into_iter().map(AttendanceResponse::from).collect())
+    }
+
+    pub async fn get_attendances_for_user_in_org(
+        &self,
+        scope: TenantScope,
+        user_id: &str,
+        org_id: &str,
+        requested_by: &str,
+        may_view_others: bool,
+    ) -> Result<Vec<AttendanceResponse>, AttendanceServiceError> {
+        if !may_view_others && user_id != requested_by {
+            return Err(AttendanceServiceError::ListForbidden);
+        }
+        let user_uuid = Uuid::parse_str(user_id)
+            .map_err(|_| AttendanceServiceError::InvalidId("user_id".into()))?;
+        let org_uuid = Uuid::parse_str(org_id)
+            .map_err(|_| AttendanceServiceError::InvalidId("organization_id".into()))?;
+
+        let result = self
+            .attendance_repository
+            .get_all_attendances_for_user_in_org(scope, user_uuid, org_uuid)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
+
+        Ok(result.into_iter().map(AttendanceResponse::from).collect())
+    }
+
+    async fn summarize(
+        &self,
+        scope: TenantScope,
+        org_id: &str,
+        user_id: Option<Uuid>,
+        group_by_user: bool,
+        request: &AttendanceSummaryRequest,
+    ) -> Result<Vec<AttendanceSummaryResponse>, AttendanceServiceError> {
+        let org_uuid = Uuid::parse_str(org_id)
+            .map_err(|_| AttendanceServiceError::InvalidId("organization_id".into()))?;
 
-        existing.clock_in = req.clock_in;
-        existing.clock_out = req.clock_out;
-        existing.date = req.date.unwrap_or(existing.date);
-        existing.method = req.method.unwrap_or_else(Default::default);
-        existing.status = req.status.unwrap_or_else(Default::default);
-        existing.attendance_type = req.attendance_type.unwrap_or_else(Default::default);
-        if let Some(loc) = req.location {
-            existing.lat = Some(loc.lat);
-            existing.long = Some(loc.long);
+        let span = (request.to - request.from).num_days() + 1;
+        if !(1..=MAX_REPORT_DAYS).contains(&span) {
+            return Err(AttendanceServiceError::InvalidReportRange);
         }
-        existing.updated_at = Utc::now();
 
-        let updated = self
+        let rows = self
             .attendance_repository
-            .update_attendance(uuid, &existing)
+            .get_attendance_summary(scope, org_uuid, user_id, group_by_user, request)
             .await
             .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
 
-        Ok(AttendanceResponse::from(updated))
+        Ok(rows
+            .into_iter()
+            .map(AttendanceSummaryResponse::from)
+            .collect())
+    }
+
+    pub async fn get_organization_summary(
+        &self,
+        scope: TenantScope,
+        org_id: &str,
+        request: AttendanceSummaryRequest,
+    ) -> Result<Vec<AttendanceSummaryResponse>, AttendanceServiceError> {
+        self.summarize(scope, org_id, None, false, &request).await
     }
 
-    pub async fn delete_attendance(&self, id: &str) -> Result<(), AttendanceServiceError> {
+    /// Per-user buckets; without `may_view_others` the caller only ever sees their own rows.
+    pub async fn get_user_summaries(
+        &self,
+        scope: TenantScope,
+        org_id: &str,
+        requested_by: &str,
+        may_view_others: bool,
+        user_id: Option<&str>,
+        request: AttendanceSummaryRequest,
+    ) -> Result<Vec<AttendanceSummaryResponse>, AttendanceServiceError> {
+        let user_id = match (user_id, may_view_others) {
+            (Some(id), false) if id != requested_by => {
+                return Err(AttendanceServiceError::ReportForbidden);
+            }
+            (Some(id), _) => Some(id),
+            (None, true) => None,
+            (None, false) => Some(requested_by),
+        };
+        let user_uuid = user_id
+            .map(Uuid::parse_str)
+            .transpose()
+            .map_err(|_| AttendanceServiceError::InvalidId("user_id".into()))?;
+
+        self.summarize(scope, org_id, user_uuid, true, &request)
+            .await
+    }
+
+    pub async fn delete_attendance(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<(), AttendanceServiceError> {
         let uuid =
             Uuid::parse_str(id).map_err(|e| AttendanceServiceError::InvalidId(e.to_string()))?;
 
+        let existing = self
+            .attendance_repository
+            .get_attendance_by_id(scope, uuid)
+            .await
+            .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?
+            .ok_or(AttendanceServiceError::NotFound)?;
+
         self.attendance_repository
-            .delete_attendance(uuid)
+            .delete_attendance(scope, uuid)
             .await
             .map_err(|e| AttendanceServiceError::DbError(e.to_string()))?;
 
+        self.publish(
+            AttendanceEventKind::Deleted,
+            &AttendanceResponse::from(existing),
+        );
+
         Ok(())
     }
 }
+
+fn parse_member_ids(
+    user_id: &str,
+    organization_id: &str,
+) -> Result<(Uuid, Uuid), AttendanceServiceError> {
+    let user_id = Uuid::parse_str(user_id)
+        .map_err(|_| AttendanceServiceError::InvalidId("user_id".into()))?;
+    let organization_id = Uuid::parse_str(organization_id)
+        .map_err(|_| AttendanceServiceError::InvalidId("organization_id".into()))?;
+
+    Ok((user_id, organization_id))
+}
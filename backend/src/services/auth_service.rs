@@ -0,0 +1,319 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{
+    auth_repository::AuthRepository, tenant_scope::TenantScope, user_repository::UserRepository,
+};
+use crate::utils::auth_utils::{
+    REFRESH_TOKEN_TTL_DAYS, generate_jwt, generate_refresh_token, hash_password,
+    hash_refresh_token, verify_password,
+};
+use chrono::{Duration, Utc};
+use shared::prelude::*;
+use shared::{
+    models::{
+        refresh_token_model::RefreshToken,
+        user_model::{NO_ORGANIZATION, User},
+    },
+    types::{
+        models::user::{role::Role, user_status::UserStatus},
+        requests::auth::{
+            login_request::LoginRequest, register_request::RegisterRequest,
+            validation_request::ValidationRequest,
+        },
+        responses::{auth_response::AuthResponse, user_response::UserResponse},
+    },
+    utils::{locale_utils::Namespace, validation_utils::validate_data},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum AuthServiceError {
+    InvalidCredentials,
+    AccountInactive,
+    DuplicateEmail,
+    PasswordRequired,
+    InvalidInput(String),
+    InvalidRefreshToken,
+    RefreshTokenReused,
+    PasswordHashingError(String),
+    JwtGenerationError(String),
+    DbError(String),
+}
+
+impl AuthServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            AuthServiceError::InvalidCredentials => {
+                messages.get_message(Namespace::Auth, "login.invalid_credentials")
+            }
+            AuthServiceError::AccountInactive => {
+                messages.get_message(Namespace::Auth, "login.inactive")
+            }
+            AuthServiceError::DuplicateEmail => {
+                messages.get_message(Namespace::Auth, "register.duplicate")
+            }
+            AuthServiceError::PasswordRequired => {
+                messages.get_message(Namespace::Auth, "register.password_required")
+            }
+            AuthServiceError::InvalidInput(msg) => msg.clone(),
+            AuthServiceError::InvalidRefreshToken => {
+                messages.get_message(Namespace::Auth, "refresh.invalid")
+            }
+            AuthServiceError::RefreshTokenReused => {
+                messages.get_message(Namespace::Auth, "refresh.reused")
+            }
+            AuthServiceError::PasswordHashingError(_) => {
+                messages.get_message(Namespace::Common, "password_hashing_failed")
+            }
+            AuthServiceError::JwtGenerationError(_) => {
+                messages.get_message(Namespace::Common, "jwt_generation_failed")
+            }
+            AuthServiceError::DbError(_) => messages.get_message(Namespace::Common, "db_error"),
+        }
+    }
+}
+
+impl From<AuthServiceError> for AppError {
+    fn from(err: AuthServiceError) -> Self {
+        match err {
+            AuthServiceError::InvalidCredentials
+            | AuthServiceError::InvalidRefreshToken
+            | AuthServiceError::RefreshTokenReused => AppError::Unauthorized,
+            AuthServiceError::AccountInactive => {
+                AppError::Forbidden("Account is not active".into())
+            }
+            AuthServiceError::DuplicateEmail => {
+                AppError::Conflict("Email already registered".into())
+            }
+            AuthServiceError::PasswordRequired => {
+                AppError::Validation("Password is required".into())
+            }
+            AuthServiceError::InvalidInput(msg) => AppError::Validation(msg),
+            AuthServiceError::PasswordHashingError(msg)
+            | AuthServiceError::JwtGenerationError(msg)
+            | AuthServiceError::DbError(msg) => AppError::Internal(msg),
+        }
+    }
+}
+
+pub struct AuthService {
+    auth_repository: Arc<AuthRepository>,
+    user_repository: Arc<UserRepository>,
+}
+
+impl AuthService {
+    pub fn new(auth_repository: Arc<AuthRepository>, user_repository: Arc<UserRepository>) -> Self {
+        Self {
+            auth_repository,
+            user_repository,
+        }
+    }
+
+    /// Self-registered users start as members of no organization; `registerOrganization` makes
+    /// them the owner of a new one.
+    pub async fn register(
+        &self,
+        request: RegisterRequest,
+        messages: &dyn MessageLookup,
+    ) -> Result<AuthResponse, AuthServiceError> {
+        let password = request
+            .password
+            .filter(|password| !password.is_empty())
+            .ok_or(AuthServiceError::PasswordRequired)?;
+
+        let validation = ValidationRequest {
+            name: Some(request.name.clone()),
+            email: Some(request.email.clone()),
+            password: Some(password.clone()),
+            password_confirmation: None,
+        };
+        validate_data(&validation, messages).map_err(|errors| {
+            let mut fields: Vec<_> = errors.field_errors().into_iter().collect();
+            fields.sort_by_key(|(field, _)| *field);
+            AuthServiceError::InvalidInput(
+                fields
+                    .into_iter()
+                    .flat_map(|(_, errors)| errors.iter())
+                    .map(|error| {
+                        error
+                            .message
+                            .as_deref()
+                            .unwrap_or(error.code.as_ref())
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        })?;
+
+        let email = request.email.trim().to_lowercase();
+        let taken = self
+            .user_repository
+            .find_registered_emails(std::slice::from_ref(&email))
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))?;
+
+        if !taken.is_empty() {
+            return Err(AuthServiceError::DuplicateEmail);
+        }
+
+        let password_hash =
+            hash_password(&password).map_err(AuthServiceError::PasswordHashingError)?;
+
+        let user = User {
+            name: request.name.trim().to_string(),
+            email,
+            role: Role::Member,
+            organization_id: NO_ORGANIZATION,
+            password_hash: Some(password_hash),
+            ..Default::default()
+        };
+
+        let saved = self
+            .user_repository
+            .register_user(&user)
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))?;
+
+        self.start_session(saved).await
+    }
+
+    pub async fn login(&self, request: LoginRequest) -> Result<AuthResponse, AuthServiceError> {
+        let user = self
+            .user_repository
+            .find_user(TenantScope::AllOrganizations, request.email.trim())
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))?
+            .ok_or(AuthServiceError::InvalidCredentials)?;
+
+        let verified = user
+            .password_hash
+            .as_deref()
+            .is_some_and(|hash| verify_password(&request.password, hash));
+
+        if !verified {
+            return Err(AuthServiceError::InvalidCredentials);
+        }
+
+        if user.status != UserStatus::Active {
+            return Err(AuthServiceError::AccountInactive);
+        }
+
+        self.start_session(user).await
+    }
+
+    pub async fn refresh(&self, refresh_token: &str) -> Result<AuthResponse, AuthServiceError> {
+        let current = self
+            .auth_repository
+            .find_refresh_token(&hash_refresh_token(refresh_token))
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))?
+            .ok_or(AuthServiceError::InvalidRefreshToken)?;
+
+        if current.is_revoked() {
+            self.revoke_family(current.family_id).await?;
+            return Err(AuthServiceError::RefreshTokenReused);
+        }
+
+        if current.is_expired() {
+            return Err(AuthServiceError::InvalidRefreshToken);
+        }
+
+        let user = self
+            .user_repository
+            .find_user_by_id(TenantScope::AllOrganizations, current.user_id)
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))?
+            .ok_or(AuthServiceError::InvalidRefreshToken)?;
+
+        if user.status != UserStatus::Active {
+            self.revoke_family(current.family_id).await?;
+            return Err(AuthServiceError::AccountInactive);
+        }
+
+        let (raw, next) = Self::new_refresh_token(user.id, current.family_id);
+
+        let rotated = self
+            .auth_repository
+            .rotate_refresh_token(current.id, &next)
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))?;
+
+        if rotated.is_none() {
+            self.revoke_family(current.family_id).await?;
+            return Err(AuthServiceError::RefreshTokenReused);
+        }
+
+        Self::auth_response(user, raw)
+    }
+
+    pub async fn logout(&self, refresh_token: &str) -> Result<(), AuthServiceError> {
+        let current = self
+            .auth_repository
+            .find_refresh_token(&hash_refresh_token(refresh_token))
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))?;
+
+        match current {
+            Some(token) => self.revoke_family(token.family_id).await,
+            None => Ok(()),
+        }
+    }
+
+    pub async fn logout_all_sessions(&self, user_id: &str) -> Result<u64, AuthServiceError> {
+        let user_id = Uuid::parse_str(user_id).map_err(|_| AuthServiceError::InvalidCredentials)?;
+
+        self.auth_repository
+            .revoke_all_for_user(user_id)
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))
+    }
+
+    async fn start_session(&self, user: User) -> Result<AuthResponse, AuthServiceError> {
+        let (raw, token) = Self::new_refresh_token(user.id, Uuid::new_v4());
+
+        self.auth_repository
+            .create_refresh_token(&token)
+            .await
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))?;
+
+        Self::auth_response(user, raw)
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), AuthServiceError> {
+        self.auth_repository
+            .revoke_family(family_id)
+            .await
+            .map(|_| ())
+            .map_err(|e| AuthServiceError::DbError(e.to_string()))
+    }
+
+    fn new_refresh_token(user_id: Uuid, family_id: Uuid) -> (String, RefreshToken) {
+        let raw = generate_refresh_token();
+        let now = Utc::now();
+
+        let token = RefreshToken {
+            id: Uuid::new_v4(),
+            user_id,
+            family_id,
+            token_hash: hash_refresh_token(&raw),
+            replaced_by: None,
+            expires_at: now + Duration::days(REFRESH_TOKEN_TTL_DAYS),
+            revoked_at: None,
+            created_at: now,
+        };
+
+        (raw, token)
+    }
+
+    fn auth_response(user: User, refresh_token: String) -> Result<AuthResponse, AuthServiceError> {
+        let token = generate_jwt(&user.id.to_string(), &user.name, &user.email)
+            .map_err(AuthServiceError::JwtGenerationError)?;
+
+        Ok(AuthResponse {
+            token,
+            refresh_token,
+            user: UserResponse::from(user),
+        })
+    }
+}
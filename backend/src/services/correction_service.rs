@@ -0,0 +1,334 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{
+    attendance_repository::AttendanceRepository,
+    correction_repository::{CorrectionApproval, CorrectionRepository},
+    tenant_scope::TenantScope,
+};
+use crate::services::attendance_service::{AttendanceEventKind, AttendanceService};
+use shared::prelude::*;
+use shared::{
+    models::attendance_correction_model::AttendanceCorrection,
+    types::{
+        models::attendance::correction_status::CorrectionStatus,
+        requests::attendance::{
+            review_correction_request::ReviewCorrectionRequest,
+            submit_correction_request::SubmitCorrectionRequest,
+        },
+        responses::{
+            attendance_correction_response::AttendanceCorrectionResponse,
+            attendance_response::AttendanceResponse,
+        },
+    },
+    utils::locale_utils::Namespace,
+};
+use std::{fmt, sync::Arc};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum CorrectionServiceError {
+    NotFound,
+    AttendanceNotFound,
+    Forbidden,
+    InvalidReason,
+    InvalidTimes,
+    NoChanges,
+    AlreadyPending,
+    NotPending,
+    Stale,
+    SelfReview,
+    InvalidId(String),
+    DbError(String),
+}
+
+impl CorrectionServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            CorrectionServiceError::NotFound => {
+                messages.get_message(Namespace::Attendance, "correction.not_found")
+            }
+            CorrectionServiceError::AttendanceNotFound => {
+                messages.get_message(Namespace::Attendance, "fetch.not_found")
+            }
+            CorrectionServiceError::Forbidden => {
+                messages.get_message(Namespace::Attendance, "correction.forbidden")
+            }
+            CorrectionServiceError::InvalidReason => {
+                messages.get_message(Namespace::Attendance, "correction.invalid_reason")
+            }
+            CorrectionServiceError::InvalidTimes => {
+                messages.get_message(Namespace::Attendance, "correction.invalid_times")
+            }
+            CorrectionServiceError::NoChanges => {
+                messages.get_message(Namespace::Attendance, "correction.no_changes")
+            }
+            CorrectionServiceError::AlreadyPending => {
+                messages.get_message(Namespace::Attendance, "correction.already_pending")
+            }
+            CorrectionServiceError::NotPending => {
+                messages.get_message(Namespace::Attendance, "correction.not_pending")
+            }
+            CorrectionServiceError::Stale => {
+                messages.get_message(Namespace::Attendance, "correction.stale")
+            }
+            CorrectionServiceError::SelfReview => {
+                messages.get_message(Namespace::Attendance, "correction.self_review")
+            }
+            CorrectionServiceError::InvalidId(_) => {
+                messages.get_message(Namespace::Attendance, "correction.invalid_id")
+            }
+            CorrectionServiceError::DbError(_) => {
+                messages.get_message(Namespace::Common, "db_error")
+            }
+        }
+    }
+}
+
+impl fmt::Display for CorrectionServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CorrectionServiceError::NotFound => write!(f, "Correction request not found"),
+            CorrectionServiceError::AttendanceNotFound => write!(f, "Attendance not found"),
+            CorrectionServiceError::Forbidden => {
+                write!(f, "You can only request corrections to your own attendance")
+            }
+            CorrectionServiceError::InvalidReason => write!(f, "Correction request needs a reason"),
+            CorrectionServiceError::InvalidTimes => {
+                write!(f, "Clock-out must be after clock-in")
+            }
+            CorrectionServiceError::NoChanges => {
+                write!(f, "Correction does not change the attendance")
+            }
+            CorrectionServiceError::AlreadyPending => {
+                write!(f, "A correction for this attendance is already pending")
+            }
+            CorrectionServiceError::NotPending => {
+                write!(f, "Correction request is no longer pending")
+            }
+            CorrectionServiceError::Stale => {
+                write!(f, "Attendance changed since the correction was requested")
+            }
+            CorrectionServiceError::SelfReview => {
+                write!(
+                    f,
+                    "You cannot review a correction you filed or one to your own attendance"
+                )
+            }
+            CorrectionServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            CorrectionServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl From<CorrectionServiceError> for AppError {
+    fn from(err: CorrectionServiceError) -> Self {
+        match err {
+            CorrectionServiceError::NotFound | CorrectionServiceError::AttendanceNotFound => {
+                AppError::NotFound(err.to_string())
+            }
+            CorrectionServiceError::Forbidden | CorrectionServiceError::SelfReview => {
+                AppError::Forbidden(err.to_string())
+            }
+            CorrectionServiceError::InvalidReason
+            | CorrectionServiceError::InvalidTimes
+            | CorrectionServiceError::NoChanges
+            | CorrectionServiceError::InvalidId(_) => AppError::Validation(err.to_string()),
+            CorrectionServiceError::AlreadyPending
+            | CorrectionServiceError::NotPending
+            | CorrectionServiceError::Stale => AppError::Conflict(err.to_string()),
+            CorrectionServiceError::DbError(_) => AppError::Internal(err.to_string()),
+        }
+    }
+}
+
+pub struct CorrectionService {
+    correction_repository: Arc<CorrectionRepository>,
+    attendance_repository: Arc<AttendanceRepository>,
+    attendance_service: Arc<AttendanceService>,
+}
+
+impl CorrectionService {
+    pub fn new(
+        correction_repository: Arc<CorrectionRepository>,
+        attendance_repository: Arc<AttendanceRepository>,
+        attendance_service: Arc<AttendanceService>,
+    ) -> Self {
+        Self {
+            correction_repository,
+            attendance_repository,
+            attendance_service,
+        }
+    }
+
+    /// Files a proposed change; `may_correct_others` lets managers file on a member's behalf.
+    pub async fn submit_correction(
+        &self,
+        scope: TenantScope,
+        requested_by: &str,
+        may_correct_others: bool,
+        request: SubmitCorrectionRequest,
+    ) -> Result<AttendanceCorrectionResponse, CorrectionServiceError> {
+        let requested_by = parse_id(requested_by, "requested_by")?;
+        let attendance_id = parse_id(&request.attendance_id, "attendance_id")?;
+
+        if request.reason.trim().is_empty() {
+            return Err(CorrectionServiceError::InvalidReason);
+        }
+
+        let attendance = self
+            .attendance_repository
+            .get_attendance_by_id(scope, attendance_id)
+            .await
+            .map_err(|e| CorrectionServiceError::DbError(e.to_string()))?
+            .ok_or(CorrectionServiceError::AttendanceNotFound)?;
+
+        if attendance.user_id != requested_by && !may_correct_others {
+            return Err(CorrectionServiceError::Forbidden);
+        }
+
+        let correction = AttendanceCorrection {
+            requested_by,
+            proposed_clock_in: request.clock_in,
+            proposed_clock_out: request.clock_out,
+            proposed_status: request.status,
+            reason: request.reason.trim().to_string(),
+            ..AttendanceCorrection::for_attendance(&attendance)
+        };
+
+        if !correction.changes_anything() {
+            return Err(CorrectionServiceError::NoChanges);
+        }
+        if let (Some(clock_in), Some(clock_out)) = (
+            correction.corrected_clock_in(),
+            correction.corrected_clock_out(),
+        ) && clock_out <= clock_in
+        {
+            return Err(CorrectionServiceError::InvalidTimes);
+        }
+
+        let created = self
+            .correction_repository
+            .create_correction(&correction)
+            .await
+            .map_err(|e| match e.as_database_error() {
+                Some(db) if db.is_unique_violation() => CorrectionServiceError::AlreadyPending,
+                _ => CorrectionServiceError::DbError(e.to_string()),
+            })?;
+
+        Ok(AttendanceCorrectionResponse::from(created))
+    }
+
+    /// Lists corrections in scope; pass `requested_by` to restrict the list to one member.
+    pub async fn get_corrections(
+        &self,
+        scope: TenantScope,
+        requested_by: Option<&str>,
+        status: Option<CorrectionStatus>,
+    ) -> Result<Vec<AttendanceCorrectionResponse>, CorrectionServiceError> {
+        let requested_by = requested_by
+            .map(|id| parse_id(id, "requested_by"))
+            .transpose()?;
+
+        let corrections = self
+            .correction_repository
+            .get_corrections(scope, requested_by, status)
+            .await
+            .map_err(|e| CorrectionServiceError::DbError(e.to_string()))?;
+
+        Ok(corrections
+            .into_iter()
+            .map(AttendanceCorrectionResponse::from)
+            .collect())
+    }
+
+    /// Approval is the only path that rewrites a recorded attendance.
+    pub async fn review_correction(
+        &self,
+        scope: TenantScope,
+        reviewer_id: &str,
+        id: &str,
+        request: ReviewCorrectionRequest,
+    ) -> Result<AttendanceCorrectionResponse, CorrectionServiceError> {
+        let id = parse_id(id, "correction_id")?;
+        let reviewer_id = parse_id(reviewer_id, "reviewer_id")?;
+
+        let existing = self
+            .correction_repository
+            .find_correction_by_id(scope, id)
+            .await
+            .map_err(|e| CorrectionServiceError::DbError(e.to_string()))?
+            .ok_or(CorrectionServiceError::NotFound)?;
+
+        let attendance = self
+            .attendance_repository
+            .get_attendance_by_id(scope, existing.attendance_id)
+            .await
+            .map_err(|e| CorrectionServiceError::DbError(e.to_string()))?
+            .ok_or(CorrectionServiceError::AttendanceNotFound)?;
+
+        // Whoever filed the correction, or owns the attendance, needs someone else to sign off.
+        if attendance.user_id == reviewer_id || existing.requested_by == reviewer_id {
+            return Err(CorrectionServiceError::SelfReview);
+        }
+        if existing.status != CorrectionStatus::Pending {
+            return Err(CorrectionServiceError::NotPending);
+        }
+
+        let note = request
+            .note
+            .as_deref()
+            .map(str::trim)
+            .filter(|note| !note.is_empty());
+
+        if !request.approve {
+            let rejected = self
+                .correction_repository
+                .reject_correction(id, reviewer_id, note)
+                .await
+                .map_err(|e| CorrectionServiceError::DbError(e.to_string()))?
+                .ok_or(CorrectionServiceError::NotPending)?;
+
+            return Ok(AttendanceCorrectionResponse::from(rejected));
+        }
+
+        let approval = self
+            .correction_repository
+            .approve_correction(id, reviewer_id, note)
+            .await
+            .map_err(|e| CorrectionServiceError::DbError(e.to_string()))?;
+
+        match approval {
+            CorrectionApproval::Applied(applied) => {
+                let (correction, attendance) = *applied;
+                self.attendance_service.publish(
+                    AttendanceEventKind::Updated,
+                    &AttendanceResponse::from(attendance),
+                );
+                Ok(AttendanceCorrectionResponse::from(correction))
+            }
+            CorrectionApproval::NotPending => Err(CorrectionServiceError::NotPending),
+            CorrectionApproval::Stale => Err(CorrectionServiceError::Stale),
+        }
+    }
+
+    pub async fn cancel_correction(
+        &self,
+        requested_by: &str,
+        id: &str,
+    ) -> Result<AttendanceCorrectionResponse, CorrectionServiceError> {
+        let id = parse_id(id, "correction_id")?;
+        let requested_by = parse_id(requested_by, "requested_by")?;
+
+        let cancelled = self
+            .correction_repository
+            .cancel_correction(id, requested_by)
+            .await
+            .map_err(|e| CorrectionServiceError::DbError(e.to_string()))?
+            .ok_or(CorrectionServiceError::NotFound)?;
+
+        Ok(AttendanceCorrectionResponse::from(cancelled))
+    }
+}
+
+fn parse_id(id: &str, field: &str) -> Result<Uuid, CorrectionServiceError> {
+    Uuid::parse_str(id).map_err(|_| CorrectionServiceError::InvalidId(field.into()))
+}
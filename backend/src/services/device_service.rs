@@ -0,0 +1,287 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{device_repository::DeviceRepository, tenant_scope::TenantScope};
+use crate::services::attendance_service::{AttendanceService, AttendanceServiceError};
+use crate::utils::auth_utils::{generate_device_api_key, hash_device_api_key};
+use shared::prelude::*;
+use shared::{
+    models::{badge_model::Badge, device_model::Device},
+    types::{
+        requests::device::{
+            assign_badge_request::AssignBadgeRequest,
+            device_check_in_request::DeviceCheckInRequest,
+            register_device_request::RegisterDeviceRequest,
+        },
+        responses::{
+            attendance_response::AttendanceResponse,
+            badge_response::BadgeResponse,
+            device_response::{DeviceResponse, RegisteredDeviceResponse},
+        },
+    },
+    utils::locale_utils::Namespace,
+};
+use std::{fmt, sync::Arc};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum DeviceServiceError {
+    DeviceNotFound,
+    InvalidDevice,
+    Unauthorized,
+    BadgeNotFound,
+    InvalidBadge,
+    DuplicateBadge,
+    MemberNotFound,
+    InvalidId(String),
+    Attendance(AttendanceServiceError),
+    DbError(String),
+}
+
+impl DeviceServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            DeviceServiceError::DeviceNotFound => {
+                messages.get_message(Namespace::Device, "not_found")
+            }
+            DeviceServiceError::InvalidDevice => messages.get_message(Namespace::Device, "invalid"),
+            DeviceServiceError::Unauthorized => {
+                messages.get_message(Namespace::Device, "unauthorized")
+            }
+            DeviceServiceError::BadgeNotFound => {
+                messages.get_message(Namespace::Device, "badge.not_found")
+            }
+            DeviceServiceError::InvalidBadge => {
+                messages.get_message(Namespace::Device, "badge.invalid")
+            }
+            DeviceServiceError::DuplicateBadge => {
+                messages.get_message(Namespace::Device, "badge.duplicate")
+            }
+            DeviceServiceError::MemberNotFound => {
+                messages.get_message(Namespace::Device, "badge.member_not_found")
+            }
+            DeviceServiceError::InvalidId(_) => {
+                messages.get_message(Namespace::Device, "invalid_id")
+            }
+            DeviceServiceError::Attendance(err) => err.to_message(messages),
+            DeviceServiceError::DbError(_) => messages.get_message(Namespace::Common, "db_error"),
+        }
+    }
+}
+
+impl fmt::Display for DeviceServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceServiceError::DeviceNotFound => write!(f, "Device not found"),
+            DeviceServiceError::InvalidDevice => write!(f, "Device needs a name"),
+            DeviceServiceError::Unauthorized => write!(f, "Unknown or revoked device key"),
+            DeviceServiceError::BadgeNotFound => write!(f, "Badge not found"),
+            DeviceServiceError::InvalidBadge => write!(f, "Badge needs a UID"),
+            DeviceServiceError::DuplicateBadge => write!(f, "Badge is already assigned"),
+            DeviceServiceError::MemberNotFound => {
+                write!(f, "User is not a member of this organization")
+            }
+            DeviceServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            DeviceServiceError::Attendance(err) => write!(f, "{}", err),
+            DeviceServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl From<DeviceServiceError> for AppError {
+    fn from(err: DeviceServiceError) -> Self {
+        match err {
+            DeviceServiceError::DeviceNotFound
+            | DeviceServiceError::BadgeNotFound
+            | DeviceServiceError::MemberNotFound => AppError::NotFound(err.to_string()),
+            DeviceServiceError::InvalidDevice
+            | DeviceServiceError::InvalidBadge
+            | DeviceServiceError::InvalidId(_) => AppError::Validation(err.to_string()),
+            DeviceServiceError::Unauthorized => AppError::Unauthorized,
+            DeviceServiceError::DuplicateBadge => AppError::Conflict(err.to_string()),
+            DeviceServiceError::Attendance(err) => AppError::from(err),
+            DeviceServiceError::DbError(_) => AppError::Internal(err.to_string()),
+        }
+    }
+}
+
+pub struct DeviceService {
+    device_repository: Arc<DeviceRepository>,
+    attendance_service: Arc<AttendanceService>,
+}
+
+impl DeviceService {
+    pub fn new(
+        device_repository: Arc<DeviceRepository>,
+        attendance_service: Arc<AttendanceService>,
+    ) -> Self {
+        Self {
+            device_repository,
+            attendance_service,
+        }
+    }
+
+    pub async fn register_device(
+        &self,
+        organization_id: &str,
+        request: RegisterDeviceRequest,
+    ) -> Result<RegisteredDeviceResponse, DeviceServiceError> {
+        if request.name.trim().is_empty() {
+            return Err(DeviceServiceError::InvalidDevice);
+        }
+
+        let api_key = generate_device_api_key();
+        let device = Device {
+            organization_id: parse_id(organization_id, "organization_id")?,
+            name: request.name,
+            kind: request.kind,
+            api_key_hash: hash_device_api_key(&api_key),
+            ..Default::default()
+        };
+
+        let created = self
+            .device_repository
+            .create_device(&device)
+            .await
+            .map_err(|e| DeviceServiceError::DbError(e.to_string()))?;
+
+        Ok(RegisteredDeviceResponse {
+            device: DeviceResponse::from(created),
+            api_key,
+        })
+    }
+
+    pub async fn get_devices(
+        &self,
+        scope: TenantScope,
+    ) -> Result<Vec<DeviceResponse>, DeviceServiceError> {
+        let devices = self
+            .device_repository
+            .get_devices(scope)
+            .await
+            .map_err(|e| DeviceServiceError::DbError(e.to_string()))?;
+
+        Ok(devices.into_iter().map(DeviceResponse::from).collect())
+    }
+
+    pub async fn revoke_device(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<(), DeviceServiceError> {
+        let id = parse_id(id, "device_id")?;
+
+        let revoked = self
+            .device_repository
+            .revoke_device(scope, id)
+            .await
+            .map_err(|e| DeviceServiceError::DbError(e.to_string()))?;
+
+        if revoked == 0 {
+            return Err(DeviceServiceError::DeviceNotFound);
+        }
+
+        Ok(())
+    }
+
+    pub async fn assign_badge(
+        &self,
+        organization_id: &str,
+        request: AssignBadgeRequest,
+    ) -> Result<BadgeResponse, DeviceServiceError> {
+        let uid = request.uid.trim();
+        if uid.is_empty() {
+            return Err(DeviceServiceError::InvalidBadge);
+        }
+
+        let badge = Badge {
+            organization_id: parse_id(organization_id, "organization_id")?,
+            user_id: parse_id(&request.user_id, "user_id")?,
+            uid: uid.to_string(),
+            ..Default::default()
+        };
+
+        let created = self
+            .device_repository
+            .create_badge(&badge)
+            .await
+            .map_err(|e| match e.as_database_error() {
+                Some(db) if db.is_unique_violation() => DeviceServiceError::DuplicateBadge,
+                _ => DeviceServiceError::DbError(e.to_string()),
+            })?
+            .ok_or(DeviceServiceError::MemberNotFound)?;
+
+        Ok(BadgeResponse::from(created))
+    }
+
+    pub async fn get_badges(
+        &self,
+        scope: TenantScope,
+    ) -> Result<Vec<BadgeResponse>, DeviceServiceError> {
+        let badges = self
+            .device_repository
+            .get_badges(scope)
+            .await
+            .map_err(|e| DeviceServiceError::DbError(e.to_string()))?;
+
+        Ok(badges.into_iter().map(BadgeResponse::from).collect())
+    }
+
+    pub async fn remove_badge(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<(), DeviceServiceError> {
+        let id = parse_id(id, "badge_id")?;
+
+        let deleted = self
+            .device_repository
+            .delete_badge(scope, id)
+            .await
+            .map_err(|e| DeviceServiceError::DbError(e.to_string()))?;
+
+        if deleted == 0 {
+            return Err(DeviceServiceError::BadgeNotFound);
+        }
+
+        Ok(())
+    }
+
+    /// Authenticates a reader by its API key and records a tap for the badge's owner.
+    pub async fn check_in(
+        &self,
+        api_key: &str,
+        request: DeviceCheckInRequest,
+    ) -> Result<AttendanceResponse, DeviceServiceError> {
+        let device = self
+            .device_repository
+            .find_device_by_key_hash(&hash_device_api_key(api_key))
+            .await
+            .map_err(|e| DeviceServiceError::DbError(e.to_string()))?
+            .filter(|device| !device.is_revoked())
+            .ok_or(DeviceServiceError::Unauthorized)?;
+
+        self.device_repository
+            .touch_device(device.id)
+            .await
+            .map_err(|e| DeviceServiceError::DbError(e.to_string()))?;
+
+        let badge = self
+            .device_repository
+            .find_badge_by_uid(device.organization_id, request.badge_uid.trim())
+            .await
+            .map_err(|e| DeviceServiceError::DbError(e.to_string()))?
+            .ok_or(DeviceServiceError::BadgeNotFound)?;
+
+        self.attendance_service
+            .record_tap(
+                badge.user_id,
+                device.organization_id,
+                device.kind.attendance_method(),
+            )
+            .await
+            .map_err(DeviceServiceError::Attendance)
+    }
+}
+
+fn parse_id(id: &str, field: &str) -> Result<Uuid, DeviceServiceError> {
+    Uuid::parse_str(id).map_err(|_| DeviceServiceError::InvalidId(field.into()))
+}
@@ -0,0 +1,248 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{attendance_repository::AttendanceRepository, tenant_scope::TenantScope};
+use crate::utils::export_utils::{EXPORT_COLUMNS, XlsxExport, csv_record};
+use actix_web::web::Bytes;
+use futures::{SinkExt, StreamExt, channel::mpsc, stream::BoxStream};
+use log::error;
+use shared::prelude::*;
+use shared::{
+    models::attendance_export_model::AttendanceExportRow,
+    types::{
+        models::attendance::export_format::ExportFormat,
+        requests::attendance::export_attendance_request::ExportAttendanceRequest,
+    },
+    utils::locale_utils::Namespace,
+};
+use std::{fmt, io::Read, sync::Arc};
+use uuid::Uuid;
+
+const MAX_EXPORT_DAYS: i64 = 366;
+const EXPORT_CHANNEL_CAPACITY: usize = 8;
+const CSV_ROWS_PER_CHUNK: usize = 500;
+const XLSX_ROW_BUFFER: usize = 500;
+const XLSX_CHUNK_BYTES: usize = 64 * 1024;
+/// Lets Excel detect UTF-8 so non-ASCII names survive opening the CSV directly.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+#[derive(Debug)]
+pub enum ExportServiceError {
+    InvalidDateRange,
+    InvalidId(String),
+    Forbidden,
+    Encoding(String),
+    Disconnected,
+    DbError(String),
+}
+
+impl ExportServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            ExportServiceError::InvalidDateRange => {
+                messages.get_message(Namespace::Attendance, "export.invalid_range")
+            }
+            ExportServiceError::InvalidId(_) => {
+                messages.get_message(Namespace::Attendance, "invalid_id")
+            }
+            ExportServiceError::Forbidden => {
+                messages.get_message(Namespace::Attendance, "forbidden")
+            }
+            ExportServiceError::Encoding(_) | ExportServiceError::Disconnected => {
+                messages.get_message(Namespace::Attendance, "export.failed")
+            }
+            ExportServiceError::DbError(_) => messages.get_message(Namespace::Common, "db_error"),
+        }
+    }
+}
+
+impl fmt::Display for ExportServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportServiceError::InvalidDateRange => write!(
+                f,
+                "Export range must end on or after its start and span at most {MAX_EXPORT_DAYS} days"
+            ),
+            ExportServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            ExportServiceError::Forbidden => {
+                write!(f, "Organization is outside the caller's tenant")
+            }
+            ExportServiceError::Encoding(msg) => write!(f, "Export encoding failed: {}", msg),
+            ExportServiceError::Disconnected => write!(f, "Export client disconnected"),
+            ExportServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ExportServiceError {}
+
+impl From<ExportServiceError> for AppError {
+    fn from(err: ExportServiceError) -> Self {
+        match err {
+            ExportServiceError::InvalidDateRange | ExportServiceError::InvalidId(_) => {
+                AppError::Validation(err.to_string())
+            }
+            ExportServiceError::Forbidden => AppError::Forbidden(err.to_string()),
+            ExportServiceError::Encoding(_)
+            | ExportServiceError::Disconnected
+            | ExportServiceError::DbError(_) => AppError::Internal(err.to_string()),
+        }
+    }
+}
+
+fn parse_id(id: &str, field: &str) -> Result<Uuid, ExportServiceError> {
+    Uuid::parse_str(id).map_err(|_| ExportServiceError::InvalidId(field.into()))
+}
+
+pub type ExportChunks = mpsc::Receiver<Result<Bytes, ExportServiceError>>;
+type ExportSender = mpsc::Sender<Result<Bytes, ExportServiceError>>;
+type ExportRows<'a> = BoxStream<'a, Result<AttendanceExportRow, sqlx::Error>>;
+
+pub struct ExportService {
+    attendance_repository: Arc<AttendanceRepository>,
+}
+
+impl ExportService {
+    pub fn new(attendance_repository: Arc<AttendanceRepository>) -> Self {
+        Self {
+            attendance_repository,
+        }
+    }
+
+    /// Validates the request up front, then encodes rows on a background task as the database
+    /// yields them. Failures after that point end the stream with an error chunk.
+    pub fn export_attendance(
+        &self,
+        scope: TenantScope,
+        org_id: &str,
+        request: &ExportAttendanceRequest,
+        messages: &dyn MessageLookup,
+    ) -> Result<ExportChunks, ExportServiceError> {
+        let organization_id = parse_id(org_id, "organization_id")?;
+        if !scope.allows(organization_id) {
+            return Err(ExportServiceError::Forbidden);
+        }
+
+        let span = (request.to - request.from).num_days() + 1;
+        if !(1..=MAX_EXPORT_DAYS).contains(&span) {
+            return Err(ExportServiceError::InvalidDateRange);
+        }
+
+        let headers: Vec<String> = EXPORT_COLUMNS
+            .iter()
+            .map(|column| {
+                messages.get_message(Namespace::Attendance, &format!("export.columns.{column}"))
+            })
+            .collect();
+
+        let (mut tx, rx) = mpsc::channel(EXPORT_CHANNEL_CAPACITY);
+        let repository = self.attendance_repository.clone();
+        let (from, to, format) = (request.from, request.to, request.format);
+
+        tokio::spawn(async move {
+            let rows = repository.stream_attendance_export(scope, organization_id, from, to);
+            let result = match format {
+                ExportFormat::Csv => write_csv(rows, &headers, &mut tx).await,
+                ExportFormat::Xlsx => write_xlsx(rows, &headers, &mut tx).await,
+            };
+
+            match result {
+                Ok(()) | Err(ExportServiceError::Disconnected) => {}
+                Err(e) => {
+                    error!("Attendance export failed: {e}");
+                    let _ = tx.send(Err(e)).await;
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
+
+async fn send_chunk(tx: &mut ExportSender, chunk: Vec<u8>) -> Result<(), ExportServiceError> {
+    tx.send(Ok(Bytes::from(chunk)))
+        .await
+        .map_err(|_| ExportServiceError::Disconnected)
+}
+
+fn into_chunk(writer: csv::Writer<Vec<u8>>) -> Result<Vec<u8>, ExportServiceError> {
+    writer
+        .into_inner()
+        .map_err(|e| ExportServiceError::Encoding(e.error().to_string()))
+}
+
+async fn write_csv(
+    mut rows: ExportRows<'_>,
+    headers: &[String],
+    tx: &mut ExportSender,
+) -> Result<(), ExportServiceError> {
+    let encoding = |e: csv::Error| ExportServiceError::Encoding(e.to_string());
+
+    let mut writer = csv::Writer::from_writer(UTF8_BOM.to_vec());
+    writer.write_record(headers).map_err(encoding)?;
+
+    let mut buffered = 0;
+    while let Some(row) = rows.next().await {
+        let row = row.map_err(|e| ExportServiceError::DbError(e.to_string()))?;
+        writer.write_record(csv_record(&row)).map_err(encoding)?;
+
+        buffered += 1;
+        if buffered == CSV_ROWS_PER_CHUNK {
+            let chunk = std::mem::replace(&mut writer, csv::Writer::from_writer(Vec::new()));
+            send_chunk(tx, into_chunk(chunk)?).await?;
+            buffered = 0;
+        }
+    }
+
+    send_chunk(tx, into_chunk(writer)?).await
+}
+
+/// XLSX is a zip archive with a central directory at the end, so it can only be sent once the
+/// last row is written. Rows are handed to a blocking task that spools them and the finished
+/// archive to disk, then streams the file back in chunks.
+async fn write_xlsx(
+    mut rows: ExportRows<'_>,
+    headers: &[String],
+    tx: &mut ExportSender,
+) -> Result<(), ExportServiceError> {
+    let (row_tx, row_rx) = tokio::sync::mpsc::channel(XLSX_ROW_BUFFER);
+    let headers = headers.to_vec();
+    let mut chunk_tx = tx.clone();
+    let encoder = tokio::task::spawn_blocking(move || encode_xlsx(&headers, row_rx, &mut chunk_tx));
+
+    while let Some(row) = rows.next().await {
+        // A closed channel means the encoder stopped early; its error is returned below.
+        if row_tx.send(row).await.is_err() {
+            break;
+        }
+    }
+    drop(row_tx);
+
+    encoder
+        .await
+        .map_err(|e| ExportServiceError::Encoding(e.to_string()))?
+}
+
+fn encode_xlsx(
+    headers: &[String],
+    mut rows: tokio::sync::mpsc::Receiver<Result<AttendanceExportRow, sqlx::Error>>,
+    tx: &mut ExportSender,
+) -> Result<(), ExportServiceError> {
+    let encoding = |e: rust_xlsxwriter::XlsxError| ExportServiceError::Encoding(e.to_string());
+
+    let mut export = XlsxExport::new(headers).map_err(encoding)?;
+    while let Some(row) = rows.blocking_recv() {
+        let row = row.map_err(|e| ExportServiceError::DbError(e.to_string()))?;
+        export.write_row(&row).map_err(encoding)?;
+    }
+
+    let mut file = export.finish().map_err(encoding)?;
+    let mut chunk = vec![0; XLSX_CHUNK_BYTES];
+    loop {
+        let read = file
+            .read(&mut chunk)
+            .map_err(|e| ExportServiceError::Encoding(e.to_string()))?;
+        if read == 0 {
+            return Ok(());
+        }
+        futures::executor::block_on(send_chunk(tx, chunk[..read].to_vec()))?;
+    }
+}
@@ -0,0 +1,186 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{geofence_repository::GeofenceRepository, tenant_scope::TenantScope};
+use shared::prelude::*;
+use shared::{
+    models::geofence_model::Geofence,
+    types::{
+        requests::geofence::geofence_request::GeofenceRequest,
+        responses::geofence_response::GeofenceResponse,
+    },
+    utils::locale_utils::Namespace,
+};
+use std::{fmt, sync::Arc};
+use uuid::Uuid;
+use validator::Validate;
+
+const MIN_POLYGON_VERTICES: usize = 3;
+
+#[derive(Debug)]
+pub enum GeofenceServiceError {
+    NotFound,
+    InvalidGeofence,
+    InvalidId(String),
+    DbError(String),
+}
+
+impl GeofenceServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            GeofenceServiceError::NotFound => {
+                messages.get_message(Namespace::Attendance, "geofence.not_found")
+            }
+            GeofenceServiceError::InvalidGeofence => {
+                messages.get_message(Namespace::Attendance, "geofence.invalid")
+            }
+            GeofenceServiceError::InvalidId(_) => {
+                messages.get_message(Namespace::Attendance, "invalid_id")
+            }
+            GeofenceServiceError::DbError(_) => {
+                messages.get_message(Namespace::Attendance, "db_error")
+            }
+        }
+    }
+}
+
+impl fmt::Display for GeofenceServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeofenceServiceError::NotFound => write!(f, "Geofence not found"),
+            GeofenceServiceError::InvalidGeofence => write!(
+                f,
+                "Geofence needs a name and either a center with a positive radius or a polygon of at least 3 valid points"
+            ),
+            GeofenceServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            GeofenceServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl From<GeofenceServiceError> for AppError {
+    fn from(err: GeofenceServiceError) -> Self {
+        match err {
+            GeofenceServiceError::NotFound => AppError::NotFound(err.to_string()),
+            GeofenceServiceError::InvalidGeofence | GeofenceServiceError::InvalidId(_) => {
+                AppError::Validation(err.to_string())
+            }
+            GeofenceServiceError::DbError(_) => AppError::Internal(err.to_string()),
+        }
+    }
+}
+
+pub struct GeofenceService {
+    geofence_repository: Arc<GeofenceRepository>,
+}
+
+impl GeofenceService {
+    pub fn new(geofence_repository: Arc<GeofenceRepository>) -> Self {
+        Self {
+            geofence_repository,
+        }
+    }
+
+    pub async fn create_geofence(
+        &self,
+        organization_id: &str,
+        request: GeofenceRequest,
+    ) -> Result<GeofenceResponse, GeofenceServiceError> {
+        let organization_id = parse_id(organization_id, "organization_id")?;
+        let geofence = Geofence {
+            organization_id,
+            ..geofence_from_request(request)?
+        };
+
+        let created = self
+            .geofence_repository
+            .create_geofence(&geofence)
+            .await
+            .map_err(|e| GeofenceServiceError::DbError(e.to_string()))?;
+
+        Ok(GeofenceResponse::from(created))
+    }
+
+    pub async fn get_geofences(
+        &self,
+        scope: TenantScope,
+    ) -> Result<Vec<GeofenceResponse>, GeofenceServiceError> {
+        let geofences = self
+            .geofence_repository
+            .get_geofences(scope)
+            .await
+            .map_err(|e| GeofenceServiceError::DbError(e.to_string()))?;
+
+        Ok(geofences.into_iter().map(GeofenceResponse::from).collect())
+    }
+
+    pub async fn update_geofence(
+        &self,
+        scope: TenantScope,
+        id: &str,
+        request: GeofenceRequest,
+    ) -> Result<GeofenceResponse, GeofenceServiceError> {
+        let id = parse_id(id, "geofence_id")?;
+        let geofence = geofence_from_request(request)?;
+
+        let updated = self
+            .geofence_repository
+            .update_geofence(scope, id, &geofence)
+            .await
+            .map_err(|e| GeofenceServiceError::DbError(e.to_string()))?
+            .ok_or(GeofenceServiceError::NotFound)?;
+
+        Ok(GeofenceResponse::from(updated))
+    }
+
+    pub async fn delete_geofence(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<(), GeofenceServiceError> {
+        let id = parse_id(id, "geofence_id")?;
+
+        let deleted = self
+            .geofence_repository
+            .delete_geofence(scope, id)
+            .await
+            .map_err(|e| GeofenceServiceError::DbError(e.to_string()))?;
+
+        if deleted == 0 {
+            return Err(GeofenceServiceError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_id(id: &str, field: &str) -> Result<Uuid, GeofenceServiceError> {
+    Uuid::parse_str(id).map_err(|_| GeofenceServiceError::InvalidId(field.into()))
+}
+
+fn geofence_from_request(request: GeofenceRequest) -> Result<Geofence, GeofenceServiceError> {
+    if request.name.trim().is_empty() {
+        return Err(GeofenceServiceError::InvalidGeofence);
+    }
+
+    match (request.center, request.radius_meters, request.polygon) {
+        (Some(center), Some(radius), None) if radius > 0.0 && center.validate().is_ok() => {
+            Ok(Geofence {
+                name: request.name,
+                center_lat: Some(center.lat),
+                center_long: Some(center.long),
+                radius_meters: Some(radius),
+                ..Default::default()
+            })
+        }
+        (None, None, Some(polygon))
+            if polygon.len() >= MIN_POLYGON_VERTICES
+                && polygon.iter().all(|point| point.validate().is_ok()) =>
+        {
+            Ok(Geofence {
+                name: request.name,
+                polygon: Some(polygon),
+                ..Default::default()
+            })
+        }
+        _ => Err(GeofenceServiceError::InvalidGeofence),
+    }
+}
@@ -0,0 +1,220 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{holiday_repository::HolidayRepository, tenant_scope::TenantScope};
+use crate::utils::ics_utils::parse_holidays;
+use chrono::NaiveDate;
+use shared::prelude::*;
+use shared::{
+    models::holiday_model::Holiday,
+    types::{
+        requests::schedule::holiday_request::HolidayRequest,
+        responses::{
+            holiday_import_response::{HolidayImportResponse, SkippedHolidayEvent},
+            holiday_response::HolidayResponse,
+        },
+    },
+    utils::locale_utils::Namespace,
+};
+use std::{fmt, sync::Arc};
+use uuid::Uuid;
+
+const MAX_IMPORTED_HOLIDAYS: usize = 1000;
+
+#[derive(Debug)]
+pub enum HolidayServiceError {
+    NotFound,
+    InvalidHoliday,
+    DuplicateHoliday,
+    InvalidCalendar(String),
+    InvalidId(String),
+    DbError(String),
+}
+
+impl HolidayServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            HolidayServiceError::NotFound => {
+                messages.get_message(Namespace::Schedule, "holiday.not_found")
+            }
+            HolidayServiceError::InvalidHoliday => {
+                messages.get_message(Namespace::Schedule, "holiday.invalid")
+            }
+            HolidayServiceError::DuplicateHoliday => {
+                messages.get_message(Namespace::Schedule, "holiday.duplicate")
+            }
+            HolidayServiceError::InvalidCalendar(_) => {
+                messages.get_message(Namespace::Schedule, "holiday.invalid_calendar")
+            }
+            HolidayServiceError::InvalidId(_) => {
+                messages.get_message(Namespace::Schedule, "invalid_id")
+            }
+            HolidayServiceError::DbError(_) => messages.get_message(Namespace::Common, "db_error"),
+        }
+    }
+}
+
+impl fmt::Display for HolidayServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HolidayServiceError::NotFound => write!(f, "Holiday not found"),
+            HolidayServiceError::InvalidHoliday => write!(f, "Holiday needs a name"),
+            HolidayServiceError::DuplicateHoliday => {
+                write!(f, "A holiday already exists on this date")
+            }
+            HolidayServiceError::InvalidCalendar(msg) => write!(f, "Invalid calendar: {}", msg),
+            HolidayServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            HolidayServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl From<HolidayServiceError> for AppError {
+    fn from(err: HolidayServiceError) -> Self {
+        match err {
+            HolidayServiceError::NotFound => AppError::NotFound(err.to_string()),
+            HolidayServiceError::InvalidHoliday
+            | HolidayServiceError::InvalidCalendar(_)
+            | HolidayServiceError::InvalidId(_) => AppError::Validation(err.to_string()),
+            HolidayServiceError::DuplicateHoliday => AppError::Conflict(err.to_string()),
+            HolidayServiceError::DbError(_) => AppError::Internal(err.to_string()),
+        }
+    }
+}
+
+pub struct HolidayService {
+    holiday_repository: Arc<HolidayRepository>,
+}
+
+impl HolidayService {
+    pub fn new(holiday_repository: Arc<HolidayRepository>) -> Self {
+        Self { holiday_repository }
+    }
+
+    pub async fn create_holiday(
+        &self,
+        organization_id: &str,
+        request: HolidayRequest,
+    ) -> Result<HolidayResponse, HolidayServiceError> {
+        let name = request.name.trim();
+        if name.is_empty() {
+            return Err(HolidayServiceError::InvalidHoliday);
+        }
+
+        let holiday = Holiday {
+            organization_id: parse_id(organization_id, "organization_id")?,
+            name: name.to_string(),
+            date: request.date,
+            recurring: request.recurring,
+            ..Default::default()
+        };
+
+        let created = self
+            .holiday_repository
+            .create_holiday(&holiday)
+            .await
+            .map_err(|e| match e.as_database_error() {
+                Some(db) if db.is_unique_violation() => HolidayServiceError::DuplicateHoliday,
+                _ => HolidayServiceError::DbError(e.to_string()),
+            })?;
+
+        Ok(HolidayResponse::from(created))
+    }
+
+    pub async fn get_holidays(
+        &self,
+        scope: TenantScope,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<HolidayResponse>, HolidayServiceError> {
+        let holidays = self
+            .holiday_repository
+            .get_holidays(scope, from, to)
+            .await
+            .map_err(|e| HolidayServiceError::DbError(e.to_string()))?;
+
+        Ok(holidays.into_iter().map(HolidayResponse::from).collect())
+    }
+
+    pub async fn delete_holiday(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<(), HolidayServiceError> {
+        let id = parse_id(id, "holiday_id")?;
+
+        let deleted = self
+            .holiday_repository
+            .delete_holiday(scope, id)
+            .await
+            .map_err(|e| HolidayServiceError::DbError(e.to_string()))?;
+
+        if deleted == 0 {
+            return Err(HolidayServiceError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    /// Imports every all-day event of an iCalendar file; existing holidays on the same date are replaced.
+    /// Events with a recurrence other than a plain yearly repeat are reported as skipped.
+    pub async fn import_holidays(
+        &self,
+        organization_id: &str,
+        ics: &str,
+        messages: &dyn MessageLookup,
+    ) -> Result<HolidayImportResponse, HolidayServiceError> {
+        let organization_id = parse_id(organization_id, "organization_id")?;
+
+        let parsed =
+            parse_holidays(ics).map_err(|e| HolidayServiceError::InvalidCalendar(e.to_string()))?;
+        if parsed.holidays.is_empty() && parsed.skipped.is_empty() {
+            return Err(HolidayServiceError::InvalidCalendar(
+                "no events found".into(),
+            ));
+        }
+        if parsed.holidays.len() > MAX_IMPORTED_HOLIDAYS {
+            return Err(HolidayServiceError::InvalidCalendar(format!(
+                "more than {MAX_IMPORTED_HOLIDAYS} days"
+            )));
+        }
+
+        let holidays: Vec<Holiday> = parsed
+            .holidays
+            .into_iter()
+            .map(|event| Holiday {
+                organization_id,
+                name: event.name,
+                date: event.date,
+                recurring: event.recurring,
+                source_uid: event.uid,
+                ..Default::default()
+            })
+            .collect();
+
+        let imported = self
+            .holiday_repository
+            .import_holidays(&holidays)
+            .await
+            .map_err(|e| HolidayServiceError::DbError(e.to_string()))?;
+
+        let reason = messages.get_message(Namespace::Schedule, "holiday.unsupported_recurrence");
+        let skipped = parsed
+            .skipped
+            .into_iter()
+            .map(|event| SkippedHolidayEvent {
+                uid: event.uid,
+                name: event.name,
+                rrule: event.rrule,
+                reason: reason.clone(),
+            })
+            .collect();
+
+        Ok(HolidayImportResponse {
+            holidays: imported.into_iter().map(HolidayResponse::from).collect(),
+            skipped,
+        })
+    }
+}
+
+fn parse_id(id: &str, field: &str) -> Result<Uuid, HolidayServiceError> {
+    Uuid::parse_str(id).map_err(|_| HolidayServiceError::InvalidId(field.into()))
+}
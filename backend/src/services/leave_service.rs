@@ -0,0 +1,242 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{leave_repository::LeaveRepository, tenant_scope::TenantScope};
+use crate::services::attendance_service::AttendanceService;
+use shared::prelude::*;
+use shared::{
+    models::leave_request_model::LeaveRequest,
+    types::{
+        models::leave::leave_status::LeaveStatus,
+        requests::leave::{
+            review_leave_request::ReviewLeaveRequest, submit_leave_request::SubmitLeaveRequest,
+        },
+        responses::leave_request_response::LeaveRequestResponse,
+    },
+    utils::locale_utils::Namespace,
+};
+use std::{fmt, sync::Arc};
+use uuid::Uuid;
+
+const MAX_LEAVE_DAYS: i64 = 366;
+
+#[derive(Debug)]
+pub enum LeaveServiceError {
+    NotFound,
+    InvalidDateRange,
+    InvalidReason,
+    Overlapping,
+    NotPending,
+    SelfReview,
+    InvalidId(String),
+    DbError(String),
+}
+
+impl LeaveServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            LeaveServiceError::NotFound => messages.get_message(Namespace::Leave, "not_found"),
+            LeaveServiceError::InvalidDateRange => {
+                messages.get_message(Namespace::Leave, "invalid_date_range")
+            }
+            LeaveServiceError::InvalidReason => {
+                messages.get_message(Namespace::Leave, "invalid_reason")
+            }
+            LeaveServiceError::Overlapping => messages.get_message(Namespace::Leave, "overlapping"),
+            LeaveServiceError::NotPending => messages.get_message(Namespace::Leave, "not_pending"),
+            LeaveServiceError::SelfReview => messages.get_message(Namespace::Leave, "self_review"),
+            LeaveServiceError::InvalidId(_) => messages.get_message(Namespace::Leave, "invalid_id"),
+            LeaveServiceError::DbError(_) => messages.get_message(Namespace::Common, "db_error"),
+        }
+    }
+}
+
+impl fmt::Display for LeaveServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaveServiceError::NotFound => write!(f, "Leave request not found"),
+            LeaveServiceError::InvalidDateRange => {
+                write!(
+                    f,
+                    "Leave must end on or after its start and span at most {MAX_LEAVE_DAYS} days"
+                )
+            }
+            LeaveServiceError::InvalidReason => write!(f, "Leave request needs a reason"),
+            LeaveServiceError::Overlapping => {
+                write!(f, "Leave overlaps another pending or approved request")
+            }
+            LeaveServiceError::NotPending => write!(f, "Leave request is no longer pending"),
+            LeaveServiceError::SelfReview => write!(f, "You cannot review your own leave request"),
+            LeaveServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            LeaveServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl From<LeaveServiceError> for AppError {
+    fn from(err: LeaveServiceError) -> Self {
+        match err {
+            LeaveServiceError::NotFound => AppError::NotFound(err.to_string()),
+            LeaveServiceError::InvalidDateRange
+            | LeaveServiceError::InvalidReason
+            | LeaveServiceError::InvalidId(_) => AppError::Validation(err.to_string()),
+            LeaveServiceError::Overlapping | LeaveServiceError::NotPending => {
+                AppError::Conflict(err.to_string())
+            }
+            LeaveServiceError::SelfReview => AppError::Forbidden(err.to_string()),
+            LeaveServiceError::DbError(_) => AppError::Internal(err.to_string()),
+        }
+    }
+}
+
+pub struct LeaveService {
+    leave_repository: Arc<LeaveRepository>,
+    attendance_service: Arc<AttendanceService>,
+}
+
+impl LeaveService {
+    pub fn new(
+        leave_repository: Arc<LeaveRepository>,
+        attendance_service: Arc<AttendanceService>,
+    ) -> Self {
+        Self {
+            leave_repository,
+            attendance_service,
+        }
+    }
+
+    pub async fn submit_leave_request(
+        &self,
+        user_id: &str,
+        organization_id: &str,
+        request: SubmitLeaveRequest,
+    ) -> Result<LeaveRequestResponse, LeaveServiceError> {
+        let span = (request.end_date - request.start_date).num_days() + 1;
+        if !(1..=MAX_LEAVE_DAYS).contains(&span) {
+            return Err(LeaveServiceError::InvalidDateRange);
+        }
+        if request.reason.trim().is_empty() {
+            return Err(LeaveServiceError::InvalidReason);
+        }
+
+        let leave = LeaveRequest {
+            organization_id: parse_id(organization_id, "organization_id")?,
+            user_id: parse_id(user_id, "user_id")?,
+            leave_type: request.leave_type,
+            start_date: request.start_date,
+            end_date: request.end_date,
+            reason: request.reason.trim().to_string(),
+            attachment_url: request
+                .attachment_url
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty()),
+            ..Default::default()
+        };
+
+        let overlapping = self
+            .leave_repository
+            .has_overlapping_leave(leave.user_id, leave.start_date, leave.end_date)
+            .await
+            .map_err(|e| LeaveServiceError::DbError(e.to_string()))?;
+        if overlapping {
+            return Err(LeaveServiceError::Overlapping);
+        }
+
+        let created = self
+            .leave_repository
+            .create_leave_request(&leave)
+            .await
+            .map_err(|e| LeaveServiceError::DbError(e.to_string()))?;
+
+        Ok(LeaveRequestResponse::from(created))
+    }
+
+    /// Lists requests in scope; pass `user_id` to restrict the list to one member.
+    pub async fn get_leave_requests(
+        &self,
+        scope: TenantScope,
+        user_id: Option<&str>,
+        status: Option<LeaveStatus>,
+    ) -> Result<Vec<LeaveRequestResponse>, LeaveServiceError> {
+        let user_id = user_id.map(|id| parse_id(id, "user_id")).transpose()?;
+
+        let requests = self
+            .leave_repository
+            .get_leave_requests(scope, user_id, status)
+            .await
+            .map_err(|e| LeaveServiceError::DbError(e.to_string()))?;
+
+        Ok(requests
+            .into_iter()
+            .map(LeaveRequestResponse::from)
+            .collect())
+    }
+
+    /// Approving a request excuses every affected day for the requester.
+    pub async fn review_leave_request(
+        &self,
+        scope: TenantScope,
+        reviewer_id: &str,
+        id: &str,
+        request: ReviewLeaveRequest,
+    ) -> Result<LeaveRequestResponse, LeaveServiceError> {
+        let id = parse_id(id, "leave_request_id")?;
+        let reviewer_id = parse_id(reviewer_id, "reviewer_id")?;
+
+        let existing = self
+            .leave_repository
+            .find_leave_request_by_id(scope, id)
+            .await
+            .map_err(|e| LeaveServiceError::DbError(e.to_string()))?
+            .ok_or(LeaveServiceError::NotFound)?;
+
+        if existing.user_id == reviewer_id {
+            return Err(LeaveServiceError::SelfReview);
+        }
+        if existing.status != LeaveStatus::Pending {
+            return Err(LeaveServiceError::NotPending);
+        }
+
+        let status = if request.approve {
+            LeaveStatus::Approved
+        } else {
+            LeaveStatus::Rejected
+        };
+        let note = request
+            .note
+            .as_deref()
+            .map(str::trim)
+            .filter(|note| !note.is_empty());
+
+        let (reviewed, excused) = self
+            .leave_repository
+            .review_leave_request(id, status, reviewer_id, note)
+            .await
+            .map_err(|e| LeaveServiceError::DbError(e.to_string()))?
+            .ok_or(LeaveServiceError::NotPending)?;
+
+        self.attendance_service.publish_excused(excused);
+
+        Ok(LeaveRequestResponse::from(reviewed))
+    }
+
+    pub async fn cancel_leave_request(
+        &self,
+        user_id: &str,
+        id: &str,
+    ) -> Result<LeaveRequestResponse, LeaveServiceError> {
+        let id = parse_id(id, "leave_request_id")?;
+        let user_id = parse_id(user_id, "user_id")?;
+
+        let cancelled = self
+            .leave_repository
+            .cancel_leave_request(id, user_id)
+            .await
+            .map_err(|e| LeaveServiceError::DbError(e.to_string()))?
+            .ok_or(LeaveServiceError::NotFound)?;
+
+        Ok(LeaveRequestResponse::from(cancelled))
+    }
+}
+
+fn parse_id(id: &str, field: &str) -> Result<Uuid, LeaveServiceError> {
+    Uuid::parse_str(id).map_err(|_| LeaveServiceError::InvalidId(field.into()))
+}
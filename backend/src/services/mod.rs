@@ -1,4 +1,13 @@
 pub mod app_service;
 pub mod attendance_service;
+pub mod auth_service;
+pub mod correction_service;
+pub mod device_service;
+pub mod export_service;
+pub mod geofence_service;
+pub mod holiday_service;
+pub mod leave_service;
 pub mod organization_service;
+pub mod schedule_service;
+pub mod subscription_service;
 pub mod user_service;
@@ -1,12 +1,23 @@
-use crate::repositories::organization_repository::OrganizationRepository;
+use crate::graphql::error::AppError;
+use crate::repositories::{
+    organization_repository::{OrganizationRepository, organization_cursor},
+    tenant_scope::TenantScope,
+};
 use log::error;
 use shared::prelude::*;
 use shared::types::requests::organization::update_organization_request::UpdateOrganizationRequest;
 use shared::{
-    models::organization_model::Organization,
+    models::{organization_model::Organization, subscription_model::Subscription},
     types::{
-        requests::organization::register_organization_request::RegisterOrganizationRequest,
-        responses::organization_response::OrganizationResponse,
+        models::subscription::subscription_plan::SubscriptionPlan,
+        requests::{
+            organization::{
+                list_organizations_request::ListOrganizationsRequest,
+                register_organization_request::RegisterOrganizationRequest,
+            },
+            page_request::PageRequest,
+        },
+        responses::{organization_response::OrganizationResponse, page_response::PageResponse},
     },
     utils::locale_utils::Namespace,
 };
@@ -18,6 +29,8 @@ pub enum OrganizationServiceError {
     NotFound,
     InvalidData,
     DuplicateEmail,
+    AlreadyInOrganization,
+    InvalidTimezone,
     DbError(String),
     JwtGenerationError(String),
 }
@@ -34,6 +47,12 @@ impl OrganizationServiceError {
             OrganizationServiceError::DuplicateEmail => {
                 messages.get_message(Namespace::Organization, "create.duplicate_email")
             }
+            OrganizationServiceError::AlreadyInOrganization => {
+                messages.get_message(Namespace::Organization, "create.already_member")
+            }
+            OrganizationServiceError::InvalidTimezone => {
+                messages.get_message(Namespace::Organization, "update.invalid_timezone")
+            }
             OrganizationServiceError::DbError(_) => {
                 messages.get_message(Namespace::Common, "db_error")
             }
@@ -44,6 +63,30 @@ impl OrganizationServiceError {
     }
 }
 
+impl From<OrganizationServiceError> for AppError {
+    fn from(err: OrganizationServiceError) -> Self {
+        match err {
+            OrganizationServiceError::NotFound => {
+                AppError::NotFound("Organization not found".into())
+            }
+            OrganizationServiceError::InvalidData => {
+                AppError::Validation("Invalid organization data".into())
+            }
+            OrganizationServiceError::DuplicateEmail => {
+                AppError::Conflict("Organization email already registered".into())
+            }
+            OrganizationServiceError::AlreadyInOrganization => {
+                AppError::Forbidden("Caller already belongs to an organization".into())
+            }
+            OrganizationServiceError::InvalidTimezone => {
+                AppError::Validation("Unknown IANA timezone".into())
+            }
+            OrganizationServiceError::DbError(msg)
+            | OrganizationServiceError::JwtGenerationError(msg) => AppError::Internal(msg),
+        }
+    }
+}
+
 pub struct OrganizationService {
     organization_repository: Arc<OrganizationRepository>,
 }
@@ -57,6 +100,7 @@ impl OrganizationService {
 
     pub async fn create_organization(
         &self,
+        owner_id: Uuid,
         new_organization: RegisterOrganizationRequest,
     ) -> Result<OrganizationResponse, OrganizationServiceError> {
         let existing_org = self
@@ -72,67 +116,115 @@ impl OrganizationService {
         let organization = Organization {
             name: new_organization.name,
             email: new_organization.email.clone(),
+            owner_id,
             logo_url: new_organization.logo_url,
             ..Default::default()
         };
+        let subscription = Subscription::new(owner_id, SubscriptionPlan::Free);
 
         let created = self
             .organization_repository
-            .create_organization(&organization)
+            .register_organization(&organization, &subscription)
             .await
-            .map_err(|e| {
-                error!(
-                    "Database error when creating organization {}: {:?}",
-                    new_organization.email, e
-                );
-                OrganizationServiceError::DbError(e.to_string())
-            })?;
+            .map_err(|e| match e.as_database_error() {
+                Some(db) if db.is_unique_violation() => OrganizationServiceError::DuplicateEmail,
+                _ => {
+                    error!(
+                        "Database error when creating organization {}: {:?}",
+                        new_organization.email, e
+                    );
+                    OrganizationServiceError::DbError(e.to_string())
+                }
+            })?
+            .ok_or(OrganizationServiceError::AlreadyInOrganization)?;
 
         Ok(OrganizationResponse::from(created))
     }
 
     pub async fn get_organization_by_id(
         &self,
+        scope: TenantScope,
         org_id: Uuid,
     ) -> Result<Option<OrganizationResponse>, OrganizationServiceError> {
         let org = self
             .organization_repository
-            .find_organization_by_id(org_id)
+            .find_organization_by_id(scope, org_id)
             .await
             .map_err(|e| OrganizationServiceError::DbError(e.to_string()))?;
 
         Ok(org.map(OrganizationResponse::from))
     }
 
-    pub async fn get_all_organizations(
+    pub async fn list_organizations(
         &self,
-    ) -> Result<Vec<OrganizationResponse>, OrganizationServiceError> {
+        scope: TenantScope,
+        request: &ListOrganizationsRequest,
+        page: PageRequest,
+    ) -> Result<PageResponse<OrganizationResponse>, OrganizationServiceError> {
+        let total = if page.count_total {
+            let total = self
+                .organization_repository
+                .count_organizations(scope, request)
+                .await
+                .map_err(|e| OrganizationServiceError::DbError(e.to_string()))?;
+            Some(total as usize)
+        } else {
+            None
+        };
+
         let orgs = self
             .organization_repository
-            .get_all_organizations()
+            .list_organizations(scope, request, &page)
             .await
             .map_err(|e| OrganizationServiceError::DbError(e.to_string()))?;
 
-        Ok(orgs.into_iter().map(OrganizationResponse::from).collect())
+        Ok(page
+            .into_page(orgs, |org| organization_cursor(request.sort_by, org), total)
+            .map(OrganizationResponse::from))
     }
 
     pub async fn update_organization(
         &self,
+        scope: TenantScope,
         org_id: Uuid,
         organization: &UpdateOrganizationRequest,
     ) -> Result<OrganizationResponse, OrganizationServiceError> {
+        let blank = |value: &Option<String>| value.as_deref().is_some_and(|v| v.trim().is_empty());
+        if blank(&organization.name) || blank(&organization.email) {
+            return Err(OrganizationServiceError::InvalidData);
+        }
+
+        if let Some(timezone) = organization.timezone.as_deref() {
+            let known = self
+                .organization_repository
+                .is_known_timezone(timezone)
+                .await
+                .map_err(|e| OrganizationServiceError::DbError(e.to_string()))?;
+            if !known {
+                return Err(OrganizationServiceError::InvalidTimezone);
+            }
+        }
+
         let updated = self
             .organization_repository
-            .update_organization(org_id, &organization)
+            .update_organization(scope, org_id, organization)
             .await
-            .map_err(|e| OrganizationServiceError::DbError(e.to_string()))?;
+            .map_err(|e| match e.as_database_error() {
+                Some(db) if db.is_unique_violation() => OrganizationServiceError::DuplicateEmail,
+                _ => OrganizationServiceError::DbError(e.to_string()),
+            })?
+            .ok_or(OrganizationServiceError::NotFound)?;
 
         Ok(OrganizationResponse::from(updated))
     }
 
-    pub async fn delete_organization(&self, org_id: Uuid) -> Result<(), OrganizationServiceError> {
+    pub async fn delete_organization(
+        &self,
+        scope: TenantScope,
+        org_id: Uuid,
+    ) -> Result<(), OrganizationServiceError> {
         self.organization_repository
-            .delete_organization(org_id)
+            .delete_organization(scope, org_id)
             .await
             .map_err(|e| OrganizationServiceError::DbError(e.to_string()))
     }
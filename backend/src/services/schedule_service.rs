@@ -0,0 +1,435 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{schedule_repository::ScheduleRepository, tenant_scope::TenantScope};
+use chrono::Utc;
+use shared::prelude::*;
+use shared::{
+    models::{group_model::Group, shift_assignment_model::ShiftAssignment, shift_model::Shift},
+    types::{
+        requests::schedule::{
+            assign_shift_request::AssignShiftRequest, shift_request::ShiftRequest,
+        },
+        responses::{
+            group_response::GroupResponse, shift_assignment_response::ShiftAssignmentResponse,
+            shift_response::ShiftResponse,
+        },
+    },
+    utils::locale_utils::Namespace,
+};
+use std::{fmt, sync::Arc};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum ScheduleServiceError {
+    ShiftNotFound,
+    GroupNotFound,
+    AssignmentNotFound,
+    MemberNotFound,
+    InvalidShift,
+    InvalidAssignment,
+    DuplicateGroup,
+    InvalidId(String),
+    DbError(String),
+}
+
+impl ScheduleServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            ScheduleServiceError::ShiftNotFound => {
+                messages.get_message(Namespace::Schedule, "shift.not_found")
+            }
+            ScheduleServiceError::GroupNotFound => {
+                messages.get_message(Namespace::Schedule, "group.not_found")
+            }
+            ScheduleServiceError::AssignmentNotFound => {
+                messages.get_message(Namespace::Schedule, "assignment.not_found")
+            }
+            ScheduleServiceError::MemberNotFound => {
+                messages.get_message(Namespace::Schedule, "group.member_not_found")
+            }
+            ScheduleServiceError::InvalidShift => {
+                messages.get_message(Namespace::Schedule, "shift.invalid")
+            }
+            ScheduleServiceError::InvalidAssignment => {
+                messages.get_message(Namespace::Schedule, "assignment.invalid")
+            }
+            ScheduleServiceError::DuplicateGroup => {
+                messages.get_message(Namespace::Schedule, "group.duplicate")
+            }
+            ScheduleServiceError::InvalidId(_) => {
+                messages.get_message(Namespace::Schedule, "invalid_id")
+            }
+            ScheduleServiceError::DbError(_) => messages.get_message(Namespace::Common, "db_error"),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleServiceError::ShiftNotFound => write!(f, "Shift not found"),
+            ScheduleServiceError::GroupNotFound => write!(f, "Group not found"),
+            ScheduleServiceError::AssignmentNotFound => write!(f, "Shift assignment not found"),
+            ScheduleServiceError::MemberNotFound => {
+                write!(f, "User is not a member of this organization")
+            }
+            ScheduleServiceError::InvalidShift => write!(
+                f,
+                "Shift needs a name, distinct start and end times, weekdays between 1 and 7 and non-negative grace minutes"
+            ),
+            ScheduleServiceError::InvalidAssignment => {
+                write!(f, "Assign a shift to exactly one user or group")
+            }
+            ScheduleServiceError::DuplicateGroup => write!(f, "Group name already exists"),
+            ScheduleServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            ScheduleServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl From<ScheduleServiceError> for AppError {
+    fn from(err: ScheduleServiceError) -> Self {
+        match err {
+            ScheduleServiceError::ShiftNotFound
+            | ScheduleServiceError::GroupNotFound
+            | ScheduleServiceError::AssignmentNotFound
+            | ScheduleServiceError::MemberNotFound => AppError::NotFound(err.to_string()),
+            ScheduleServiceError::InvalidShift
+            | ScheduleServiceError::InvalidAssignment
+            | ScheduleServiceError::InvalidId(_) => AppError::Validation(err.to_string()),
+            ScheduleServiceError::DuplicateGroup => AppError::Conflict(err.to_string()),
+            ScheduleServiceError::DbError(_) => AppError::Internal(err.to_string()),
+        }
+    }
+}
+
+pub struct ScheduleService {
+    schedule_repository: Arc<ScheduleRepository>,
+}
+
+impl ScheduleService {
+    pub fn new(schedule_repository: Arc<ScheduleRepository>) -> Self {
+        Self {
+            schedule_repository,
+        }
+    }
+
+    pub async fn create_shift(
+        &self,
+        organization_id: &str,
+        request: ShiftRequest,
+    ) -> Result<ShiftResponse, ScheduleServiceError> {
+        let organization_id = parse_id(organization_id, "organization_id")?;
+        let shift = Shift {
+            organization_id,
+            ..shift_from_request(request)?
+        };
+
+        let created = self
+            .schedule_repository
+            .create_shift(&shift)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        Ok(ShiftResponse::from(created))
+    }
+
+    pub async fn get_shifts(
+        &self,
+        scope: TenantScope,
+    ) -> Result<Vec<ShiftResponse>, ScheduleServiceError> {
+        let shifts = self
+            .schedule_repository
+            .get_shifts(scope)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        Ok(shifts.into_iter().map(ShiftResponse::from).collect())
+    }
+
+    pub async fn update_shift(
+        &self,
+        scope: TenantScope,
+        id: &str,
+        request: ShiftRequest,
+    ) -> Result<ShiftResponse, ScheduleServiceError> {
+        let id = parse_id(id, "shift_id")?;
+        let shift = shift_from_request(request)?;
+
+        let updated = self
+            .schedule_repository
+            .update_shift(scope, id, &shift)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?
+            .ok_or(ScheduleServiceError::ShiftNotFound)?;
+
+        Ok(ShiftResponse::from(updated))
+    }
+
+    pub async fn delete_shift(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<(), ScheduleServiceError> {
+        let id = parse_id(id, "shift_id")?;
+
+        let deleted = self
+            .schedule_repository
+            .delete_shift(scope, id)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        if deleted == 0 {
+            return Err(ScheduleServiceError::ShiftNotFound);
+        }
+
+        Ok(())
+    }
+
+    pub async fn create_group(
+        &self,
+        organization_id: &str,
+        name: String,
+    ) -> Result<GroupResponse, ScheduleServiceError> {
+        let group = Group {
+            organization_id: parse_id(organization_id, "organization_id")?,
+            name,
+            ..Default::default()
+        };
+
+        let created = self
+            .schedule_repository
+            .create_group(&group)
+            .await
+            .map_err(|e| match e.as_database_error() {
+                Some(db) if db.is_unique_violation() => ScheduleServiceError::DuplicateGroup,
+                _ => ScheduleServiceError::DbError(e.to_string()),
+            })?;
+
+        Ok(GroupResponse::from(created))
+    }
+
+    pub async fn get_groups(
+        &self,
+        scope: TenantScope,
+    ) -> Result<Vec<GroupResponse>, ScheduleServiceError> {
+        let groups = self
+            .schedule_repository
+            .get_groups(scope)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        Ok(groups.into_iter().map(GroupResponse::from).collect())
+    }
+
+    pub async fn get_group_member_ids(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<String>, ScheduleServiceError> {
+        let group_id = parse_id(group_id, "group_id")?;
+
+        let member_ids = self
+            .schedule_repository
+            .get_group_member_ids(group_id)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        Ok(member_ids.into_iter().map(|id| id.to_string()).collect())
+    }
+
+    pub async fn add_group_member(
+        &self,
+        scope: TenantScope,
+        group_id: &str,
+        user_id: &str,
+    ) -> Result<(), ScheduleServiceError> {
+        let group = self.find_group(scope, group_id).await?;
+        let user_id = parse_id(user_id, "user_id")?;
+
+        let is_member = self
+            .schedule_repository
+            .is_member_of_organization(user_id, group.organization_id)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        if !is_member {
+            return Err(ScheduleServiceError::MemberNotFound);
+        }
+
+        self.schedule_repository
+            .add_group_member(&group, user_id)
+            .await
+            .map(|_| ())
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))
+    }
+
+    pub async fn remove_group_member(
+        &self,
+        scope: TenantScope,
+        group_id: &str,
+        user_id: &str,
+    ) -> Result<(), ScheduleServiceError> {
+        let group = self.find_group(scope, group_id).await?;
+        let user_id = parse_id(user_id, "user_id")?;
+
+        let removed = self
+            .schedule_repository
+            .remove_group_member(group.id, user_id)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        if removed == 0 {
+            return Err(ScheduleServiceError::MemberNotFound);
+        }
+
+        Ok(())
+    }
+
+    pub async fn assign_shift(
+        &self,
+        scope: TenantScope,
+        request: AssignShiftRequest,
+    ) -> Result<ShiftAssignmentResponse, ScheduleServiceError> {
+        let shift = self.find_shift(scope, &request.shift_id).await?;
+
+        let assignment = match (request.user_id.as_deref(), request.group_id.as_deref()) {
+            (Some(user_id), None) => {
+                let user_id = parse_id(user_id, "user_id")?;
+                let is_member = self
+                    .schedule_repository
+                    .is_member_of_organization(user_id, shift.organization_id)
+                    .await
+                    .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+                if !is_member {
+                    return Err(ScheduleServiceError::MemberNotFound);
+                }
+
+                ShiftAssignment {
+                    id: Uuid::new_v4(),
+                    shift_id: shift.id,
+                    user_id: Some(user_id),
+                    group_id: None,
+                    created_at: Utc::now(),
+                }
+            }
+            (None, Some(group_id)) => {
+                let group = self
+                    .find_group(TenantScope::Organization(shift.organization_id), group_id)
+                    .await?;
+
+                ShiftAssignment {
+                    id: Uuid::new_v4(),
+                    shift_id: shift.id,
+                    user_id: None,
+                    group_id: Some(group.id),
+                    created_at: Utc::now(),
+                }
+            }
+            _ => return Err(ScheduleServiceError::InvalidAssignment),
+        };
+
+        let created = self
+            .schedule_repository
+            .create_assignment(&assignment)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        Ok(ShiftAssignmentResponse::from(created))
+    }
+
+    pub async fn get_assignments_for_shift(
+        &self,
+        scope: TenantScope,
+        shift_id: &str,
+    ) -> Result<Vec<ShiftAssignmentResponse>, ScheduleServiceError> {
+        let shift = self.find_shift(scope, shift_id).await?;
+
+        let assignments = self
+            .schedule_repository
+            .get_assignments_for_shift(shift.id)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        Ok(assignments
+            .into_iter()
+            .map(ShiftAssignmentResponse::from)
+            .collect())
+    }
+
+    pub async fn unassign_shift(
+        &self,
+        scope: TenantScope,
+        assignment_id: &str,
+    ) -> Result<(), ScheduleServiceError> {
+        let id = parse_id(assignment_id, "assignment_id")?;
+
+        let deleted = self
+            .schedule_repository
+            .delete_assignment(scope, id)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?;
+
+        if deleted == 0 {
+            return Err(ScheduleServiceError::AssignmentNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_shift(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<Shift, ScheduleServiceError> {
+        let id = parse_id(id, "shift_id")?;
+
+        self.schedule_repository
+            .find_shift_by_id(scope, id)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?
+            .ok_or(ScheduleServiceError::ShiftNotFound)
+    }
+
+    async fn find_group(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<Group, ScheduleServiceError> {
+        let id = parse_id(id, "group_id")?;
+
+        self.schedule_repository
+            .find_group_by_id(scope, id)
+            .await
+            .map_err(|e| ScheduleServiceError::DbError(e.to_string()))?
+            .ok_or(ScheduleServiceError::GroupNotFound)
+    }
+}
+
+fn parse_id(id: &str, field: &str) -> Result<Uuid, ScheduleServiceError> {
+    Uuid::parse_str(id).map_err(|_| ScheduleServiceError::InvalidId(field.into()))
+}
+
+fn shift_from_request(request: ShiftRequest) -> Result<Shift, ScheduleServiceError> {
+    let valid = !request.name.trim().is_empty()
+        && request.start_time != request.end_time
+        && !request.weekdays.is_empty()
+        && request.weekdays.iter().all(|day| (1..=7).contains(day))
+        && request.grace_minutes >= 0;
+
+    if !valid {
+        return Err(ScheduleServiceError::InvalidShift);
+    }
+
+    let mut weekdays = request.weekdays;
+    weekdays.sort_unstable();
+    weekdays.dedup();
+
+    Ok(Shift {
+        name: request.name,
+        start_time: request.start_time,
+        end_time: request.end_time,
+        weekdays,
+        grace_minutes: request.grace_minutes,
+        ..Default::default()
+    })
+}
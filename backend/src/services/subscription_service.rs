@@ -0,0 +1,111 @@
+use crate::graphql::error::AppError;
+use crate::repositories::{
+    subscription_repository::SubscriptionRepository, tenant_scope::TenantScope,
+};
+use chrono::Utc;
+use shared::prelude::*;
+use shared::{
+    types::responses::subscription_response::SubscriptionResponse, utils::locale_utils::Namespace,
+};
+use std::{fmt, sync::Arc};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum SubscriptionServiceError {
+    NotFound,
+    InvalidId(String),
+    DbError(String),
+}
+
+impl SubscriptionServiceError {
+    pub fn to_message(&self, messages: &dyn MessageLookup) -> String {
+        match self {
+            SubscriptionServiceError::NotFound => {
+                messages.get_message(Namespace::Organization, "subscription.not_found")
+            }
+            SubscriptionServiceError::InvalidId(_) => {
+                messages.get_message(Namespace::Organization, "subscription.invalid_id")
+            }
+            SubscriptionServiceError::DbError(_) => {
+                messages.get_message(Namespace::Common, "db_error")
+            }
+        }
+    }
+}
+
+impl fmt::Display for SubscriptionServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubscriptionServiceError::NotFound => write!(f, "Subscription not found"),
+            SubscriptionServiceError::InvalidId(msg) => write!(f, "Invalid ID: {}", msg),
+            SubscriptionServiceError::DbError(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SubscriptionServiceError {}
+
+impl From<SubscriptionServiceError> for AppError {
+    fn from(err: SubscriptionServiceError) -> Self {
+        match err {
+            SubscriptionServiceError::NotFound => AppError::NotFound(err.to_string()),
+            SubscriptionServiceError::InvalidId(_) => AppError::Validation(err.to_string()),
+            SubscriptionServiceError::DbError(_) => AppError::Internal(err.to_string()),
+        }
+    }
+}
+
+pub struct SubscriptionService {
+    subscription_repository: Arc<SubscriptionRepository>,
+}
+
+impl SubscriptionService {
+    pub fn new(subscription_repository: Arc<SubscriptionRepository>) -> Self {
+        Self {
+            subscription_repository,
+        }
+    }
+
+    pub async fn get_organization_subscription(
+        &self,
+        scope: TenantScope,
+        org_id: &str,
+    ) -> Result<SubscriptionResponse, SubscriptionServiceError> {
+        let organization_id = Uuid::parse_str(org_id)
+            .map_err(|_| SubscriptionServiceError::InvalidId("organization_id".into()))?;
+
+        self.subscription_repository
+            .find_subscription_for_organization(scope, organization_id)
+            .await
+            .map_err(|e| SubscriptionServiceError::DbError(e.to_string()))?
+            .map(SubscriptionResponse::from)
+            .ok_or(SubscriptionServiceError::NotFound)
+    }
+
+    /// Moves lapsed paid subscriptions into their grace period and, once that ends, to expired.
+    /// Returns how many subscriptions changed.
+    pub async fn refresh_statuses(&self) -> Result<u64, SubscriptionServiceError> {
+        let lapsing = self
+            .subscription_repository
+            .find_lapsing_subscriptions(Utc::now())
+            .await
+            .map_err(|e| SubscriptionServiceError::DbError(e.to_string()))?;
+
+        let changes: Vec<_> = lapsing
+            .into_iter()
+            .filter_map(|mut subscription| {
+                let previous = subscription.status;
+                subscription.update_status_based_on_time();
+                (subscription.status != previous).then_some((previous, subscription))
+            })
+            .collect();
+        if changes.is_empty() {
+            return Ok(0);
+        }
+
+        self.subscription_repository
+            .update_statuses(&changes)
+            .await
+            .map_err(|e| SubscriptionServiceError::DbError(e.to_string()))
+    }
+}
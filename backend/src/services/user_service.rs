@@ -1,13 +1,39 @@
 use crate::graphql::error::AppError;
-use crate::repositories::user_repository::UserRepository;
+use crate::repositories::{
+    tenant_scope::TenantScope,
+    user_repository::{UserRepository, user_cursor},
+};
+use crate::utils::auth_utils::hash_password;
+use crate::utils::csv_utils::{CsvError, parse_user_rows};
 use shared::{
     models::user_model::User,
+    prelude::MessageLookup,
+    types::models::user::role::Role,
     types::requests::{
-        auth::register_request::RegisterRequest, user::update_user_request::UpdateUserRequest,
+        auth::register_request::RegisterRequest,
+        page_request::PageRequest,
+        user::{list_users_request::ListUsersRequest, update_user_request::UpdateUserRequest},
+    },
+    types::responses::{
+        page_response::PageResponse,
+        user_import_response::{UserImportResponse, UserImportRowError},
+        user_response::UserResponse,
     },
-    types::responses::user_response::UserResponse,
+    utils::locale_utils::Namespace,
+    validations::{email::validate_email, name::validate_name},
 };
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
+use uuid::Uuid;
+use validator::ValidationError;
+
+const MAX_IMPORTED_USERS: usize = 2000;
+
+fn validation_message(error: ValidationError) -> String {
+    error
+        .message
+        .map(|message| message.into_owned())
+        .unwrap_or_else(|| error.code.into_owned())
+}
 
 pub struct UserService {
     pub user_repository: Arc<UserRepository>,
@@ -18,10 +44,24 @@ impl UserService {
         Self { user_repository }
     }
 
-    pub async fn register_user(&self, new_user: RegisterRequest) -> Result<UserResponse, AppError> {
+    pub async fn add_member(
+        &self,
+        organization_id: &str,
+        new_user: RegisterRequest,
+        role: Role,
+    ) -> Result<UserResponse, AppError> {
+        if !role.is_assignable() {
+            return Err(AppError::Forbidden(format!(
+                "Role {role} cannot be assigned"
+            )));
+        }
+
+        let organization_id =
+            Uuid::parse_str(organization_id).map_err(|e| AppError::Validation(e.to_string()))?;
+
         let exists = self
             .user_repository
-            .find_user(&new_user.email)
+            .find_user(TenantScope::AllOrganizations, &new_user.email)
             .await
             .map_err(|e| AppError::Internal(e.to_string()))?;
 
@@ -29,9 +69,19 @@ impl UserService {
             return Err(AppError::Conflict("Email already registered".into()));
         }
 
+        let password_hash = new_user
+            .password
+            .as_deref()
+            .map(hash_password)
+            .transpose()
+            .map_err(AppError::Internal)?;
+
         let user = User {
             name: new_user.name,
-            email: new_user.email.clone(),
+            email: new_user.email,
+            organization_id,
+            role,
+            password_hash,
             ..Default::default()
         };
 
@@ -44,44 +94,287 @@ impl UserService {
         Ok(UserResponse::from(saved))
     }
 
-    pub async fn get_all_users(&self) -> Result<Vec<UserResponse>, AppError> {
+    /// Checks every row and reports problems per row; the valid rows are then inserted in one
+    /// transaction unless `dry_run` is set.
+    pub async fn import_users(
+        &self,
+        organization_id: &str,
+        csv: &str,
+        dry_run: bool,
+        messages: &dyn MessageLookup,
+    ) -> Result<UserImportResponse, AppError> {
+        let organization_id =
+            Uuid::parse_str(organization_id).map_err(|e| AppError::Validation(e.to_string()))?;
+
+        let rows = parse_user_rows(csv).map_err(|e| {
+            AppError::Validation(match e {
+                CsvError::MissingColumns(columns) => format!(
+                    "{} {}",
+                    messages.get_message(Namespace::User, "import.missing_columns"),
+                    columns.join(", ")
+                ),
+                CsvError::Malformed(_) => messages.get_message(Namespace::User, "import.malformed"),
+            })
+        })?;
+        if rows.is_empty() || rows.len() > MAX_IMPORTED_USERS {
+            return Err(AppError::Validation(
+                messages.get_message(Namespace::User, "import.row_count"),
+            ));
+        }
+
+        let emails: Vec<String> = rows.iter().map(|row| row.email.to_lowercase()).collect();
+        let registered: HashSet<String> = self
+            .user_repository
+            .find_registered_emails(&emails)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .into_iter()
+            .collect();
+
+        let total_rows = rows.len();
+        let mut seen = HashSet::new();
+        let mut users = Vec::new();
+        let mut errors = Vec::new();
+
+        for row in rows {
+            let mut row_errors = Vec::new();
+
+            if let Err(e) = validate_name(messages, &row.name) {
+                row_errors.push(("name", validation_message(e)));
+            }
+
+            let email = row.email.to_lowercase();
+            if let Err(e) = validate_email(messages, &row.email) {
+                row_errors.push(("email", validation_message(e)));
+            } else if registered.contains(&email) {
+                row_errors.push((
+                    "email",
+                    messages.get_message(Namespace::User, "import.email_taken"),
+                ));
+            } else if !seen.insert(email) {
+                row_errors.push((
+                    "email",
+                    messages.get_message(Namespace::User, "import.duplicate_email"),
+                ));
+            }
+
+            let role = if row.role.is_empty() {
+                Some(Role::Member)
+            } else {
+                row.role
+                    .parse::<Role>()
+                    .ok()
+                    .filter(|role| role.is_assignable())
+            };
+            if role.is_none() {
+                row_errors.push((
+                    "role",
+                    messages.get_message(Namespace::User, "import.invalid_role"),
+                ));
+            }
+
+            match role {
+                Some(role) if row_errors.is_empty() => users.push(User {
+                    name: row.name,
+                    email: row.email,
+                    organization_id,
+                    role,
+                    ..Default::default()
+                }),
+                _ => errors.extend(row_errors.into_iter().map(|(field, message)| {
+                    UserImportRowError {
+                        row: row.row,
+                        email: Some(row.email.clone()).filter(|email| !email.is_empty()),
+                        field: field.to_string(),
+                        message,
+                    }
+                })),
+            }
+        }
+
+        if !dry_run && !users.is_empty() {
+            users = self
+                .user_repository
+                .register_users(&users)
+                .await
+                .map_err(|e| match e.as_database_error() {
+                    Some(db) if db.is_unique_violation() => AppError::Conflict(
+                        messages.get_message(Namespace::User, "import.email_taken"),
+                    ),
+                    _ => AppError::Internal(e.to_string()),
+                })?;
+        }
+
+        Ok(UserImportResponse {
+            dry_run,
+            total_rows,
+            users: users.into_iter().map(UserResponse::from).collect(),
+            errors,
+        })
+    }
+
+    pub async fn assign_role(
+        &self,
+        scope: TenantScope,
+        user_id: &str,
+        role: Role,
+    ) -> Result<UserResponse, AppError> {
+        if !role.is_assignable() {
+            return Err(AppError::Forbidden(format!(
+                "Role {role} cannot be assigned"
+            )));
+        }
+
+        let uuid = Uuid::parse_str(user_id).map_err(|e| AppError::Validation(e.to_string()))?;
+
+        let target = self
+            .user_repository
+            .find_user_by_id(scope, uuid)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .ok_or_else(|| AppError::NotFound("User not found".into()))?;
+
+        if !target.role.is_assignable() {
+            return Err(AppError::Forbidden(format!(
+                "Role of a {} cannot be changed",
+                target.role
+            )));
+        }
+
+        let updated = self
+            .user_repository
+            .update_role(scope, uuid, role)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .ok_or_else(|| AppError::NotFound("User not found".into()))?;
+
+        Ok(UserResponse::from(updated))
+    }
+
+    pub async fn list_users(
+        &self,
+        scope: TenantScope,
+        request: &ListUsersRequest,
+        page: PageRequest,
+    ) -> Result<PageResponse<UserResponse>, AppError> {
+        let total = if page.count_total {
+            let total = self
+                .user_repository
+                .count_users(scope, request)
+                .await
+                .map_err(|e| AppError::Internal(e.to_string()))?;
+            Some(total as usize)
+        } else {
+            None
+        };
+
         let users = self
             .user_repository
-            .get_all_users()
+            .list_users(scope, request, &page)
             .await
             .map_err(|e| AppError::Internal(e.to_string()))?;
 
-        Ok(users.into_iter().map(UserResponse::from).collect())
+        Ok(page
+            .into_page(users, |user| user_cursor(request.sort_by, user), total)
+            .map(UserResponse::from))
+    }
+
+    pub async fn get_user(
+        &self,
+        scope: TenantScope,
+        email: &str,
+    ) -> Result<Option<UserResponse>, AppError> {
+        let user = self
+            .user_repository
+            .find_user(scope, email)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        Ok(user.map(UserResponse::from))
     }
 
-    pub async fn get_user(&self, email: &str) -> Result<Option<UserResponse>, AppError> {
+    pub async fn get_user_by_id(
+        &self,
+        scope: TenantScope,
+        id: &str,
+    ) -> Result<Option<UserResponse>, AppError> {
+        let uuid = Uuid::parse_str(id).map_err(|e| AppError::Validation(e.to_string()))?;
+
         let user = self
             .user_repository
-            .find_user(email)
+            .find_user_by_id(scope, uuid)
             .await
             .map_err(|e| AppError::Internal(e.to_string()))?;
 
         Ok(user.map(UserResponse::from))
     }
 
+    pub async fn get_users_by_organization(
+        &self,
+        org_id: &str,
+    ) -> Result<Vec<UserResponse>, AppError> {
+        let uuid = Uuid::parse_str(org_id).map_err(|e| AppError::Validation(e.to_string()))?;
+
+        let users = self
+            .user_repository
+            .get_users_by_organization(uuid)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        Ok(users.into_iter().map(UserResponse::from).collect())
+    }
+
+    /// Only users whose role could be assigned may be edited or deleted, so owners stay out of
+    /// reach of admins.
+    async fn ensure_manageable(&self, scope: TenantScope, email: &str) -> Result<(), AppError> {
+        let target = self
+            .user_repository
+            .find_user(scope, email)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .ok_or_else(|| AppError::NotFound("User not found".into()))?;
+
+        if !target.role.is_assignable() {
+            return Err(AppError::Forbidden(format!(
+                "A {} cannot be edited or deleted",
+                target.role
+            )));
+        }
+
+        Ok(())
+    }
+
     pub async fn update_user(
         &self,
+        scope: TenantScope,
         email: &str,
         update: UpdateUserRequest,
     ) -> Result<UserResponse, AppError> {
+        self.ensure_manageable(scope, email).await?;
+
         let updated = self
             .user_repository
-            .update_user(email, update)
+            .update_user(scope, email, update)
             .await
-            .map_err(|e| AppError::Internal(e.to_string()))?;
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .ok_or_else(|| AppError::NotFound("User not found".into()))?;
 
         Ok(UserResponse::from(updated))
     }
 
-    pub async fn delete_user(&self, email: &str) -> Result<(), AppError> {
-        self.user_repository
-            .delete_user(email)
+    pub async fn delete_user(&self, scope: TenantScope, email: &str) -> Result<(), AppError> {
+        self.ensure_manageable(scope, email).await?;
+
+        let deleted = self
+            .user_repository
+            .delete_user(scope, email)
             .await
-            .map_err(|e| AppError::Internal(e.to_string()))
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("User not found".into()));
+        }
+
+        Ok(())
     }
 }
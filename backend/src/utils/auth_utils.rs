@@ -1,4 +1,4 @@
-use crate::constants::{COOKIE_NAME, JWT_SECRET_KEY};
+use crate::constants::{COOKIE_NAME, JWT_SECRET_KEY, REFRESH_COOKIE_NAME};
 use actix_web::cookie::time::Duration as CookieDuration;
 use actix_web::cookie::{Cookie, SameSite};
 use argon2::{
@@ -9,25 +9,56 @@ use chrono::{Duration as ChronoDuration, Utc};
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
 use log::error;
 use phonenumber::{country, parse};
+use rand::RngCore;
 use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
 use shared::types::auth::claims::Claims;
 use validator::ValidationError;
 
+pub const ACCESS_TOKEN_TTL_MINUTES: i64 = 15;
+pub const REFRESH_TOKEN_TTL_DAYS: i64 = 30;
+const REFRESH_TOKEN_BYTES: usize = 32;
+const DEVICE_API_KEY_PREFIX: &str = "adx_";
+
 pub fn generate_cookie(token: String) -> Cookie<'static> {
     Cookie::build(COOKIE_NAME.as_str(), token)
         .http_only(true)
         .secure(true)
         .same_site(SameSite::Strict)
         .path("/")
-        .max_age(CookieDuration::days(7))
+        .max_age(CookieDuration::minutes(ACCESS_TOKEN_TTL_MINUTES))
+        .finish()
+}
+
+pub fn clear_cookie() -> Cookie<'static> {
+    let mut cookie = generate_cookie(String::new());
+    cookie.make_removal();
+    cookie
+}
+
+/// Only sent to `/graphql`, where `refreshToken` and `logout` read it.
+pub fn generate_refresh_cookie(token: String) -> Cookie<'static> {
+    Cookie::build(REFRESH_COOKIE_NAME.as_str(), token)
+        .http_only(true)
+        .secure(true)
+        .same_site(SameSite::Strict)
+        .path("/graphql")
+        .max_age(CookieDuration::days(REFRESH_TOKEN_TTL_DAYS))
         .finish()
 }
 
-pub fn generate_jwt(name: &str, email: &str) -> Result<String, String> {
+pub fn clear_refresh_cookie() -> Cookie<'static> {
+    let mut cookie = generate_refresh_cookie(String::new());
+    cookie.make_removal();
+    cookie
+}
+
+pub fn generate_jwt(user_id: &str, name: &str, email: &str) -> Result<String, String> {
     let secret_key = JWT_SECRET_KEY.as_bytes();
-    let expiration = Utc::now() + ChronoDuration::hours(24);
+    let expiration = Utc::now() + ChronoDuration::minutes(ACCESS_TOKEN_TTL_MINUTES);
 
     let claims = Claims {
+        sub: user_id.to_owned(),
         name: name.to_owned(),
         email: email.to_owned(),
         exp: expiration.timestamp() as usize,
@@ -57,6 +88,24 @@ pub fn verify_jwt(token: &str) -> Result<Claims, String> {
     })
 }
 
+pub fn generate_refresh_token() -> String {
+    let mut bytes = [0u8; REFRESH_TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+pub fn hash_refresh_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+pub fn generate_device_api_key() -> String {
+    format!("{DEVICE_API_KEY_PREFIX}{}", generate_refresh_token())
+}
+
+pub fn hash_device_api_key(api_key: &str) -> String {
+    hex::encode(Sha256::digest(api_key.as_bytes()))
+}
+
 pub fn hash_password(password: &str) -> Result<String, String> {
     let salt = SaltString::generate(&mut OsRng);
     let argon2 = Argon2::default();
@@ -0,0 +1,82 @@
+use std::fmt;
+
+const USER_COLUMNS: [&str; 3] = ["name", "email", "role"];
+
+/// One data record of a user import; `row` is the file line it starts on, with the header on
+/// line 1, and `role` is left raw so it can be reported per row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserCsvRow {
+    pub row: usize,
+    pub name: String,
+    pub email: String,
+    pub role: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CsvError {
+    MissingColumns(Vec<String>),
+    Malformed(String),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::MissingColumns(columns) => {
+                write!(f, "Missing column(s): {}", columns.join(", "))
+            }
+            CsvError::Malformed(msg) => write!(f, "Malformed CSV: {}", msg),
+        }
+    }
+}
+
+/// Reads a `name,email,role` file. Headers are matched case-insensitively and may appear in
+/// any order; extra columns and blank lines are ignored.
+pub fn parse_user_rows(input: &str) -> Result<Vec<UserCsvRow>, CsvError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(input.trim_start_matches('\u{feff}').as_bytes());
+
+    let headers = reader
+        .headers()
+        .map_err(|e| CsvError::Malformed(e.to_string()))?
+        .clone();
+    let position = |column: &str| {
+        headers
+            .iter()
+            .position(|header| header.eq_ignore_ascii_case(column))
+    };
+
+    let missing: Vec<String> = USER_COLUMNS
+        .iter()
+        .filter(|column| position(column).is_none())
+        .map(|column| column.to_string())
+        .collect();
+    let (Some(name), Some(email), Some(role)) =
+        (position("name"), position("email"), position("role"))
+    else {
+        return Err(CsvError::MissingColumns(missing));
+    };
+
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| CsvError::Malformed(e.to_string()))?;
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+
+        // Blank lines and quoted line breaks make the record index drift from the file line.
+        let row = record
+            .position()
+            .map_or(index + 2, |position| position.line() as usize);
+        let field = |column: usize| record.get(column).unwrap_or_default().to_string();
+        rows.push(UserCsvRow {
+            row,
+            name: field(name),
+            email: field(email),
+            role: field(role),
+        });
+    }
+
+    Ok(rows)
+}
@@ -0,0 +1,122 @@
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use shared::models::attendance_export_model::AttendanceExportRow;
+use std::{
+    fs::File,
+    io::{Seek, SeekFrom},
+};
+
+/// Column keys, in output order, under `export.columns` in the attendance messages.
+pub const EXPORT_COLUMNS: [&str; 15] = [
+    "id",
+    "user_id",
+    "user_name",
+    "user_email",
+    "date",
+    "clock_in",
+    "clock_out",
+    "worked_hours",
+    "status",
+    "attendance_type",
+    "method",
+    "lat",
+    "long",
+    "distance_meters",
+    "outside_geofence",
+];
+
+const CSV_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+pub fn csv_record(row: &AttendanceExportRow) -> [String; 15] {
+    [
+        row.id.to_string(),
+        row.user_id.to_string(),
+        row.user_name.clone(),
+        row.user_email.clone(),
+        row.local_date.to_string(),
+        optional(row.clock_in.map(|t| t.format(CSV_DATETIME_FORMAT))),
+        optional(row.clock_out.map(|t| t.format(CSV_DATETIME_FORMAT))),
+        optional(row.worked_hours.map(|h| format!("{h:.2}"))),
+        row.status.to_string(),
+        row.attendance_type.to_string(),
+        row.method.to_string(),
+        optional(row.lat),
+        optional(row.long),
+        optional(row.distance_meters),
+        row.outside_geofence.to_string(),
+    ]
+}
+
+/// Single-sheet workbook whose rows are flushed to a temp file as they are written; the finished
+/// archive is written to another temp file. All methods do blocking disk I/O.
+pub struct XlsxExport {
+    workbook: Workbook,
+    next_row: u32,
+    date: Format,
+    datetime: Format,
+    hours: Format,
+}
+
+impl XlsxExport {
+    pub fn new(headers: &[String]) -> Result<Self, XlsxError> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet_with_constant_memory();
+        let bold = Format::new().set_bold();
+
+        for (col, header) in headers.iter().enumerate() {
+            worksheet.write_string_with_format(0, col as u16, header, &bold)?;
+        }
+        worksheet.set_freeze_panes(1, 0)?;
+
+        Ok(Self {
+            workbook,
+            next_row: 1,
+            date: Format::new().set_num_format("yyyy-mm-dd"),
+            datetime: Format::new().set_num_format("yyyy-mm-dd hh:mm:ss"),
+            hours: Format::new().set_num_format("0.00"),
+        })
+    }
+
+    pub fn write_row(&mut self, row: &AttendanceExportRow) -> Result<(), XlsxError> {
+        let r = self.next_row;
+        let worksheet = self.workbook.worksheet_from_index(0)?;
+
+        worksheet.write_string(r, 0, row.id.to_string())?;
+        worksheet.write_string(r, 1, row.user_id.to_string())?;
+        worksheet.write_string(r, 2, &row.user_name)?;
+        worksheet.write_string(r, 3, &row.user_email)?;
+        worksheet.write_datetime_with_format(r, 4, row.local_date, &self.date)?;
+        if let Some(clock_in) = row.clock_in {
+            worksheet.write_datetime_with_format(r, 5, clock_in, &self.datetime)?;
+        }
+        if let Some(clock_out) = row.clock_out {
+            worksheet.write_datetime_with_format(r, 6, clock_out, &self.datetime)?;
+        }
+        if let Some(hours) = row.worked_hours {
+            worksheet.write_number_with_format(r, 7, hours, &self.hours)?;
+        }
+        worksheet.write_string(r, 8, row.status.to_string())?;
+        worksheet.write_string(r, 9, row.attendance_type.to_string())?;
+        worksheet.write_string(r, 10, row.method.to_string())?;
+        for (col, value) in [(11, row.lat), (12, row.long), (13, row.distance_meters)] {
+            if let Some(value) = value {
+                worksheet.write_number(r, col, value)?;
+            }
+        }
+        worksheet.write_boolean(r, 14, row.outside_geofence)?;
+
+        self.next_row += 1;
+        Ok(())
+    }
+
+    /// Returns the finished workbook as an unnamed temp file, rewound to its start.
+    pub fn finish(mut self) -> Result<File, XlsxError> {
+        let mut file = tempfile::tempfile()?;
+        self.workbook.save_to_writer(&mut file)?;
+        file.seek(SeekFrom::Start(0))?;
+        Ok(file)
+    }
+}
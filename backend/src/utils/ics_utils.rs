@@ -0,0 +1,202 @@
+use chrono::{Duration, NaiveDate};
+use std::fmt;
+
+/// Events spanning more days than this are treated as malformed rather than expanded.
+const MAX_EVENT_DAYS: i64 = 31;
+
+/// One calendar day taken from a VEVENT; multi-day events yield one entry per day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsHoliday {
+    pub uid: Option<String>,
+    pub name: String,
+    pub date: NaiveDate,
+    pub recurring: bool,
+}
+
+/// A VEVENT whose RRULE is not a plain yearly repeat; such events are reported, not imported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsSkippedEvent {
+    pub uid: Option<String>,
+    pub name: String,
+    pub rrule: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct IcsCalendar {
+    pub holidays: Vec<IcsHoliday>,
+    pub skipped: Vec<IcsSkippedEvent>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum IcsError {
+    NotACalendar,
+    InvalidDate(String),
+    EventTooLong(String),
+}
+
+impl fmt::Display for IcsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IcsError::NotACalendar => write!(f, "Missing BEGIN:VCALENDAR"),
+            IcsError::InvalidDate(value) => write!(f, "Invalid date '{}'", value),
+            IcsError::EventTooLong(name) => {
+                write!(f, "Event '{}' spans more than {MAX_EVENT_DAYS} days", name)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct PendingEvent {
+    uid: Option<String>,
+    summary: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    rrule: Option<String>,
+}
+
+/// Parses the all-day events of an iCalendar file. Only the fields a holiday
+/// calendar needs are read; a bare `FREQ=YEARLY` RRULE marks the entry as recurring,
+/// and events with any other RRULE are skipped.
+pub fn parse_holidays(ics: &str) -> Result<IcsCalendar, IcsError> {
+    let lines = unfold(ics);
+    if !lines
+        .iter()
+        .any(|line| line.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err(IcsError::NotACalendar);
+    }
+
+    let mut calendar = IcsCalendar::default();
+    let mut current: Option<PendingEvent> = None;
+
+    for line in &lines {
+        let Some((name, value)) = split_property(line) else {
+            continue;
+        };
+
+        match (name.as_str(), current.as_mut()) {
+            ("BEGIN", _) if value.eq_ignore_ascii_case("VEVENT") => {
+                current = Some(PendingEvent::default());
+            }
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                if let Some(event) = current.take() {
+                    expand(event, &mut calendar)?;
+                }
+            }
+            ("UID", Some(event)) => event.uid = Some(value.to_string()),
+            ("SUMMARY", Some(event)) => event.summary = Some(unescape(value)),
+            ("DTSTART", Some(event)) => event.start = Some(value.to_string()),
+            ("DTEND", Some(event)) => event.end = Some(value.to_string()),
+            ("RRULE", Some(event)) => event.rrule = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    Ok(calendar)
+}
+
+fn expand(event: PendingEvent, calendar: &mut IcsCalendar) -> Result<(), IcsError> {
+    let Some(start_value) = event.start.as_deref() else {
+        return Ok(());
+    };
+    let start = parse_date(start_value)?;
+
+    // DTEND is exclusive for all-day events and inclusive of its day for timed ones.
+    let end = match event.end.as_deref() {
+        Some(value) if value.contains('T') => parse_date(value)? + Duration::days(1),
+        Some(value) => parse_date(value)?,
+        None => start + Duration::days(1),
+    };
+
+    let name = event
+        .summary
+        .filter(|summary| !summary.trim().is_empty())
+        .unwrap_or_else(|| "Holiday".to_string());
+    let recurring = match event.rrule {
+        Some(rrule) if is_plain_yearly(&rrule) => true,
+        Some(rrule) => {
+            calendar.skipped.push(IcsSkippedEvent {
+                uid: event.uid,
+                name,
+                rrule,
+            });
+            return Ok(());
+        }
+        None => false,
+    };
+
+    let days = (end - start).num_days().max(1);
+    if days > MAX_EVENT_DAYS {
+        return Err(IcsError::EventTooLong(name));
+    }
+
+    calendar.holidays.extend((0..days).map(|offset| IcsHoliday {
+        uid: event.uid.clone(),
+        name: name.clone(),
+        date: start + Duration::days(offset),
+        recurring,
+    }));
+    Ok(())
+}
+
+/// Holidays only recur on the same month and day, so BY*, UNTIL, COUNT and
+/// intervals other than 1 cannot be represented.
+fn is_plain_yearly(rrule: &str) -> bool {
+    let mut yearly = false;
+    for part in rrule.split(';').filter(|part| !part.is_empty()) {
+        let Some((key, value)) = part.split_once('=') else {
+            return false;
+        };
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" if value.eq_ignore_ascii_case("YEARLY") => yearly = true,
+            "INTERVAL" if value == "1" => {}
+            "WKST" => {}
+            _ => return false,
+        }
+    }
+    yearly
+}
+
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.lines() {
+        let raw = raw.trim_end_matches('\r');
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(previous)) => previous.push_str(continuation),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// Splits `NAME;PARAM=...:VALUE` into the upper-cased name and the value.
+fn split_property(line: &str) -> Option<(String, &str)> {
+    let (head, value) = line.split_once(':')?;
+    let name = head.split(';').next()?.trim().to_ascii_uppercase();
+    Some((name, value.trim()))
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, IcsError> {
+    value
+        .get(..8)
+        .and_then(|digits| NaiveDate::parse_from_str(digits, "%Y%m%d").ok())
+        .ok_or_else(|| IcsError::InvalidDate(value.to_string()))
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push(' '),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out.trim().to_string()
+}
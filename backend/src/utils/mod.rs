@@ -1,3 +1,7 @@
 pub mod auth_utils;
+pub mod csv_utils;
+pub mod export_utils;
 pub mod http_utils;
+pub mod ics_utils;
 pub mod locale_utils;
+pub mod qr_utils;
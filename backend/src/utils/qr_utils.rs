@@ -0,0 +1,93 @@
+use crate::constants::QR_SECRET_KEY;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const QR_ROTATION_SECONDS: i64 = 30;
+const QR_GRACE_SECONDS: i64 = 5;
+const QR_CODE_VERSION: &str = "v1";
+const NO_GEOFENCE: &str = "-";
+
+/// A QR check-in code: valid for one rotation window, optionally bound to a geofence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrPayload {
+    pub organization_id: Uuid,
+    pub geofence_id: Option<Uuid>,
+    pub window: i64,
+}
+
+impl QrPayload {
+    pub fn current(organization_id: Uuid, geofence_id: Option<Uuid>, now: DateTime<Utc>) -> Self {
+        Self {
+            organization_id,
+            geofence_id,
+            window: now.timestamp().div_euclid(QR_ROTATION_SECONDS),
+        }
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp((self.window + 1) * QR_ROTATION_SECONDS, 0).unwrap_or_default()
+    }
+
+    /// Redemptions made before this instant belong to codes that have expired since.
+    pub fn redemption_cutoff(now: DateTime<Utc>) -> DateTime<Utc> {
+        now - Duration::seconds(QR_ROTATION_SECONDS + QR_GRACE_SECONDS)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now.timestamp() >= (self.window + 1) * QR_ROTATION_SECONDS + QR_GRACE_SECONDS
+    }
+
+    pub fn sign(&self) -> String {
+        let message = self.message();
+        let signature = hex::encode(mac_for(&message).finalize().into_bytes());
+        format!("{message}.{signature}")
+    }
+
+    /// Parses a signed code, returning `None` if it is malformed or the signature does not match.
+    pub fn verify(code: &str) -> Option<Self> {
+        let (message, signature) = code.rsplit_once('.')?;
+        let signature = hex::decode(signature).ok()?;
+        mac_for(message).verify_slice(&signature).ok()?;
+
+        let mut parts = message.split('.');
+        if parts.next()? != QR_CODE_VERSION {
+            return None;
+        }
+        let organization_id = Uuid::parse_str(parts.next()?).ok()?;
+        let geofence_id = match parts.next()? {
+            NO_GEOFENCE => None,
+            id => Some(Uuid::parse_str(id).ok()?),
+        };
+        let window = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            organization_id,
+            geofence_id,
+            window,
+        })
+    }
+
+    fn message(&self) -> String {
+        let geofence = self
+            .geofence_id
+            .map_or_else(|| NO_GEOFENCE.to_string(), |id| id.to_string());
+        format!(
+            "{QR_CODE_VERSION}.{}.{geofence}.{}",
+            self.organization_id, self.window
+        )
+    }
+}
+
+fn mac_for(message: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(QR_SECRET_KEY.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(message.as_bytes());
+    mac
+}
@@ -0,0 +1,192 @@
+use crate::constants::COOKIE_NAME;
+use crate::graphql::middleware::auth::{tenant_scope, validate_token};
+use crate::repositories::tenant_scope::TenantScope;
+use crate::services::{app_service::AppService, attendance_service::AttendanceService};
+use crate::utils::locale_utils::get_lang;
+use actix_web::{HttpRequest, HttpResponse, rt, web};
+use actix_ws::Message;
+use futures::StreamExt;
+use serde_json::{Value, json};
+use shared::{
+    prelude::MessageLookup,
+    types::{
+        models::user::permissions::Permission,
+        responses::{
+            api_response::{ApiResponse, ErrorDetails},
+            user_response::UserResponse,
+        },
+        ws_types::{AttendanceWsMessage, AttendanceWsResponse},
+    },
+    utils::locale_utils::{Messages, Namespace},
+};
+
+/// Tokens are never read from the query string, where they would end up in access logs.
+fn extract_token(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .map(str::to_string)
+        .or_else(|| {
+            req.cookie(COOKIE_NAME.as_str())
+                .map(|cookie| cookie.value().to_string())
+        })
+}
+
+async fn resolve_caller(
+    req: &HttpRequest,
+    app_service: &AppService,
+) -> Option<(UserResponse, TenantScope)> {
+    let claims = validate_token(&extract_token(req)?).ok()?;
+    let user_id = claims.get("sub").and_then(|v| v.as_str())?;
+
+    let user = app_service
+        .user_service
+        .get_user_by_id(TenantScope::AllOrganizations, user_id)
+        .await
+        .ok()??;
+
+    let scope = tenant_scope(&user).ok()?;
+    Some((user, scope))
+}
+
+pub async fn attendance_ws(
+    req: HttpRequest,
+    body: web::Payload,
+    app_service: web::Data<AppService>,
+) -> actix_web::Result<HttpResponse> {
+    let messages = Messages::new(get_lang(&req));
+
+    let Some((caller, scope)) = resolve_caller(&req, &app_service).await else {
+        return Ok(HttpResponse::Unauthorized().json(ApiResponse::<()>::error(
+            messages.get_message(Namespace::Common, "unauthorized"),
+            None,
+        )));
+    };
+
+    let (response, mut session, mut stream) = actix_ws::handle(&req, body)?;
+    let attendance_service = app_service.attendance_service.clone();
+
+    rt::spawn(async move {
+        while let Some(Ok(msg)) = stream.next().await {
+            let sent = match msg {
+                Message::Text(text) => {
+                    let reply =
+                        handle_text(&attendance_service, &caller, scope, &messages, &text).await;
+                    session.text(reply).await
+                }
+                Message::Ping(bytes) => session.pong(&bytes).await,
+                Message::Close(reason) => {
+                    let _ = session.close(reason).await;
+                    return;
+                }
+                _ => Ok(()),
+            };
+
+            if sent.is_err() {
+                return;
+            }
+        }
+
+        let _ = session.close(None).await;
+    });
+
+    Ok(response)
+}
+
+async fn handle_text(
+    svc: &AttendanceService,
+    caller: &UserResponse,
+    scope: TenantScope,
+    messages: &Messages,
+    text: &str,
+) -> String {
+    let response = match serde_json::from_str::<AttendanceWsMessage>(text) {
+        Ok(msg) => dispatch(svc, caller, scope, messages, msg).await,
+        Err(e) => ApiResponse::error(
+            messages.get_message(Namespace::Common, "invalid_data"),
+            Some(ErrorDetails {
+                details: Some(json!(e.to_string())),
+            }),
+        ),
+    };
+
+    serde_json::to_string(&AttendanceWsResponse { response }).unwrap_or_default()
+}
+
+/// Same rules as the GraphQL attendance queries and `attendanceEvents`: writes need
+/// `ManageAttendance`, and callers without it only ever see their own records.
+async fn dispatch(
+    svc: &AttendanceService,
+    caller: &UserResponse,
+    scope: TenantScope,
+    messages: &Messages,
+    msg: AttendanceWsMessage,
+) -> ApiResponse<Value> {
+    let may_manage = caller.role.has_permission(Permission::ManageAttendance);
+    if !caller.role.has_permission(Permission::ViewAttendance) {
+        return ApiResponse::error(
+            messages.get_message(Namespace::Common, "unauthorized"),
+            None,
+        );
+    }
+
+    match msg {
+        AttendanceWsMessage::Create(_) | AttendanceWsMessage::Delete(_) if !may_manage => {
+            ApiResponse::error(
+                messages.get_message(Namespace::Attendance, "manage.forbidden"),
+                None,
+            )
+        }
+        AttendanceWsMessage::Create(request) => match svc.create_attendance(scope, request).await {
+            Ok(created) => ApiResponse::success(
+                messages.get_message(Namespace::Attendance, "create.success"),
+                Some(json!(created)),
+            ),
+            Err(e) => ApiResponse::error(e.to_message(messages), None),
+        },
+        AttendanceWsMessage::Read(id) => match svc
+            .get_attendance_by_id(scope, &id, &caller.id, may_manage)
+            .await
+        {
+            Ok(Some(attendance)) => ApiResponse::success(
+                messages.get_message(Namespace::Attendance, "fetch.success"),
+                Some(json!(attendance)),
+            ),
+            Ok(None) => ApiResponse::error(
+                messages.get_message(Namespace::Attendance, "fetch.not_found"),
+                None,
+            ),
+            Err(e) => ApiResponse::error(e.to_message(messages), None),
+        },
+        AttendanceWsMessage::ReadAll => {
+            let attendances = if may_manage {
+                svc.get_all_attendances(scope).await
+            } else {
+                svc.get_attendances_for_user_in_org(
+                    scope,
+                    &caller.id,
+                    &caller.organization_id,
+                    &caller.id,
+                    may_manage,
+                )
+                .await
+            };
+
+            match attendances {
+                Ok(attendances) => ApiResponse::success(
+                    messages.get_message(Namespace::Attendance, "fetch.all_success"),
+                    Some(json!(attendances)),
+                ),
+                Err(e) => ApiResponse::error(e.to_message(messages), None),
+            }
+        }
+        AttendanceWsMessage::Delete(id) => match svc.delete_attendance(scope, &id).await {
+            Ok(()) => ApiResponse::success(
+                messages.get_message(Namespace::Attendance, "delete.success"),
+                None,
+            ),
+            Err(e) => ApiResponse::error(e.to_message(messages), None),
+        },
+    }
+}
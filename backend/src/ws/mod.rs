@@ -0,0 +1 @@
+pub mod attendance_ws;
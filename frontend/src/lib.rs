@@ -1,10 +1,12 @@
 use crate::pages::dashboard::admin::index::AdminDashboardPage;
 use components::layout::main_layout::MainLayout;
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use leptos_fetch::QueryClient;
 use leptos_meta::*;
 use leptos_router::{components::*, path};
 use pages::auth::register::Register;
+use store::auth::actions::{keep_session_alive, refresh_session};
 use store::auth::state::AuthStore;
 
 mod components;
@@ -25,6 +27,13 @@ pub fn App() -> impl IntoView {
     let auth = RwSignal::new(AuthStore::default());
     provide_context(auth);
 
+    // A still-valid refresh cookie restores the session after a reload.
+    spawn_local(async move {
+        if refresh_session(auth).await.is_ok() {
+            keep_session_alive(auth);
+        }
+    });
+
     view! {
         <Html attr:lang="en" attr:dir="ltr" attr:data-theme="dark" />
 
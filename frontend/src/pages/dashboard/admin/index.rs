@@ -1,4 +1,5 @@
 use crate::constants::API_BASE_URL;
+use crate::store::auth::api::send_with_refresh;
 use chrono::{DateTime, Utc};
 use gloo_net::http::Request;
 use leptos::component;
@@ -24,7 +25,7 @@ struct ApiResponseWrapper<T> {
 async fn fetch_list<T: 'static + Clone + DeserializeOwned + PartialEq + Send + Sync>(
     url: String,
 ) -> Result<Vec<T>, ServerFnError> {
-    let resp = Request::get(&url).send().await?;
+    let resp = send_with_refresh(|| Request::get(&url)).await?;
     let wrapper = resp.json::<ApiResponseWrapper<T>>().await?;
     wrapper
         .data
@@ -1,10 +1,16 @@
 use crate::store::auth::api::{
-    LoginPayload, RegisterPayload, send_login_request, send_register_request,
+    LoginPayload, RegisterPayload, send_login_request, send_refresh_request, send_register_request,
 };
 use crate::store::auth::handlers::{handle_login_response, handle_register_response};
-use crate::store::auth::helpers::{init_auth_loading, show_error};
+use crate::store::auth::helpers::{end_session, init_auth_loading, show_error};
 use crate::store::auth::state::AuthStore;
-use leptos::prelude::RwSignal;
+use leptos::prelude::{GetUntracked, RwSignal, Set, set_interval_with_handle};
+use leptos::task::spawn_local;
+use std::time::Duration;
+
+/// Access tokens live for 15 minutes; renewing a minute early keeps requests from ever carrying
+/// an expired one.
+const SESSION_REFRESH_INTERVAL: Duration = Duration::from_secs(14 * 60);
 
 pub async fn sign_in_with_email(
     auth: RwSignal<AuthStore>,
@@ -15,6 +21,7 @@ pub async fn sign_in_with_email(
     match send_login_request(&payload).await {
         Ok(response) => {
             handle_login_response(&auth, response, "Login success".into()).await;
+            keep_session_alive(auth);
             Ok(())
         }
         Err(error) => {
@@ -33,6 +40,7 @@ pub async fn register_with_email(
     match send_register_request(&payload).await {
         Ok(response) => {
             handle_register_response(&auth, response, "Register success".into()).await;
+            keep_session_alive(auth);
             Ok(())
         }
         Err(error) => {
@@ -41,3 +49,36 @@ pub async fn register_with_email(
         }
     }
 }
+
+/// Swaps the refresh cookie for a new session; signs the user out when it is no longer valid.
+pub async fn refresh_session(auth: RwSignal<AuthStore>) -> Result<(), String> {
+    match send_refresh_request().await {
+        Ok(user) => {
+            auth.get_untracked().user.set(Some(user));
+            Ok(())
+        }
+        Err(error) => {
+            end_session(&auth);
+            Err(error)
+        }
+    }
+}
+
+/// Renews the session shortly before each access token expires.
+pub fn keep_session_alive(auth: RwSignal<AuthStore>) {
+    let timer = auth.get_untracked().refresh_timer;
+    if let Some(previous) = timer.get_untracked() {
+        previous.clear();
+    }
+
+    let handle = set_interval_with_handle(
+        move || {
+            spawn_local(async move {
+                let _ = refresh_session(auth).await;
+            })
+        },
+        SESSION_REFRESH_INTERVAL,
+    )
+    .ok();
+    timer.set(handle);
+}
@@ -1,4 +1,5 @@
 use crate::store::auth::state::{AuthStore, ToastType};
+use leptos::prelude::GetUntracked;
 use leptos::prelude::RwSignal;
 use leptos::prelude::Set;
 use leptos::prelude::Update;
@@ -25,6 +26,17 @@ pub fn show_error(auth: &RwSignal<AuthStore>, message: String) {
     });
 }
 
+/// Drops the signed-in user and stops renewing their session.
+pub fn end_session(auth: &RwSignal<AuthStore>) {
+    auth.update(|auth| {
+        if let Some(timer) = auth.refresh_timer.get_untracked() {
+            timer.clear();
+        }
+        auth.refresh_timer.set(None);
+        auth.user.set(None);
+    });
+}
+
 pub fn show_success(auth: &RwSignal<AuthStore>, message: String, user: UserResponse) {
     auth.update(|auth| {
         auth.user.set(Some(user));
@@ -1,4 +1,7 @@
-use leptos::{context::use_context, prelude::RwSignal};
+use leptos::{
+    context::use_context,
+    prelude::{IntervalHandle, RwSignal},
+};
 use serde::{Deserialize, Serialize};
 use shared::types::responses::user_response::UserResponse;
 
@@ -10,6 +13,7 @@ pub struct AuthStore {
     pub is_toast_visible: RwSignal<bool>,
     pub toast_type: RwSignal<Option<ToastType>>,
     pub user: RwSignal<Option<UserResponse>>,
+    pub refresh_timer: RwSignal<Option<IntervalHandle>>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
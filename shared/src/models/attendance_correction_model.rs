@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+use crate::models::attendance_model::Attendance;
+use crate::types::models::attendance::{
+    attendance_status::AttendanceStatus, correction_status::CorrectionStatus,
+};
+
+/// A reviewed change to an attendance; `original_*` snapshots the row when the request was filed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct AttendanceCorrection {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub attendance_id: Uuid,
+    pub requested_by: Uuid,
+    pub original_clock_in: Option<DateTime<Utc>>,
+    pub original_clock_out: Option<DateTime<Utc>>,
+    pub original_status: AttendanceStatus,
+    pub proposed_clock_in: Option<DateTime<Utc>>,
+    pub proposed_clock_out: Option<DateTime<Utc>>,
+    pub proposed_status: Option<AttendanceStatus>,
+    pub reason: String,
+    pub status: CorrectionStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl AttendanceCorrection {
+    pub fn for_attendance(attendance: &Attendance) -> Self {
+        Self {
+            organization_id: attendance.organization_id,
+            attendance_id: attendance.id,
+            original_clock_in: attendance.clock_in,
+            original_clock_out: attendance.clock_out,
+            original_status: attendance.status,
+            ..Default::default()
+        }
+    }
+
+    pub fn corrected_clock_in(&self) -> Option<DateTime<Utc>> {
+        self.proposed_clock_in.or(self.original_clock_in)
+    }
+
+    pub fn corrected_clock_out(&self) -> Option<DateTime<Utc>> {
+        self.proposed_clock_out.or(self.original_clock_out)
+    }
+
+    pub fn corrected_status(&self) -> AttendanceStatus {
+        self.proposed_status.unwrap_or(self.original_status)
+    }
+
+    pub fn changes_anything(&self) -> bool {
+        self.corrected_clock_in() != self.original_clock_in
+            || self.corrected_clock_out() != self.original_clock_out
+            || self.corrected_status() != self.original_status
+    }
+}
+
+impl Default for AttendanceCorrection {
+    fn default() -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            attendance_id: Uuid::new_v4(),
+            requested_by: Uuid::new_v4(),
+            original_clock_in: None,
+            original_clock_out: None,
+            original_status: AttendanceStatus::default(),
+            proposed_clock_in: None,
+            proposed_clock_out: None,
+            proposed_status: None,
+            reason: String::new(),
+            status: CorrectionStatus::default(),
+            reviewed_by: None,
+            reviewed_at: None,
+            review_note: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
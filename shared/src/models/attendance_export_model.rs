@@ -0,0 +1,32 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+use crate::types::models::attendance::{
+    attendance_method::AttendanceMethod, attendance_status::AttendanceStatus,
+    attendance_type::AttendanceType,
+};
+
+/// Attendance joined with its member, with clock times in the organization's timezone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct AttendanceExportRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub user_email: String,
+    pub local_date: NaiveDate,
+    pub clock_in: Option<NaiveDateTime>,
+    pub clock_out: Option<NaiveDateTime>,
+    pub worked_hours: Option<f64>,
+    pub status: AttendanceStatus,
+    pub attendance_type: AttendanceType,
+    pub method: AttendanceMethod,
+    pub lat: Option<f64>,
+    pub long: Option<f64>,
+    pub distance_meters: Option<f64>,
+    pub outside_geofence: bool,
+}
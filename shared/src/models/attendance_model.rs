@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -19,15 +19,27 @@ pub struct Attendance {
     pub clock_in: Option<DateTime<Utc>>,
     pub clock_out: Option<DateTime<Utc>>,
     pub date: DateTime<Utc>,
+    pub local_date: NaiveDate,
     pub method: AttendanceMethod,
     pub status: AttendanceStatus,
     pub attendance_type: AttendanceType,
     pub lat: Option<f64>,
     pub long: Option<f64>,
+    pub distance_meters: Option<f64>,
+    pub outside_geofence: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl Attendance {
+    pub fn worked_minutes(&self) -> Option<i64> {
+        match (self.clock_in, self.clock_out) {
+            (Some(clock_in), Some(clock_out)) => Some((clock_out - clock_in).num_minutes()),
+            _ => None,
+        }
+    }
+}
+
 impl Default for Attendance {
     fn default() -> Self {
         let now = Utc::now();
@@ -38,11 +50,14 @@ impl Default for Attendance {
             clock_in: None,
             clock_out: None,
             date: now,
+            local_date: now.date_naive(),
             method: AttendanceMethod::default(),
             status: AttendanceStatus::default(),
             attendance_type: AttendanceType::default(),
             lat: None,
             long: None,
+            distance_meters: None,
+            outside_geofence: false,
             created_at: now,
             updated_at: now,
         }
@@ -0,0 +1,21 @@
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+/// One aggregated bucket of attendance rows; `user_id` is set only for per-user summaries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct AttendanceSummary {
+    pub period_start: NaiveDate,
+    pub user_id: Option<Uuid>,
+    pub present: i64,
+    pub late: i64,
+    pub absent: i64,
+    pub excused: i64,
+    pub attendance_rate: Option<f64>,
+    pub average_clock_in: Option<NaiveTime>,
+    pub total_hours: f64,
+}
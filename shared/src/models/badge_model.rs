@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct Badge {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub uid: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Default for Badge {
+    fn default() -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            uid: String::new(),
+            created_at: Utc::now(),
+        }
+    }
+}
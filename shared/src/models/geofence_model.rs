@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+use crate::types::models::attendance::geolocation::GeoLocation;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct Geofence {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub center_lat: Option<f64>,
+    pub center_long: Option<f64>,
+    pub radius_meters: Option<f64>,
+    #[cfg_attr(feature = "backend", sqlx(json(nullable)))]
+    pub polygon: Option<Vec<GeoLocation>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Geofence {
+    pub fn center(&self) -> Option<GeoLocation> {
+        Some(GeoLocation {
+            lat: self.center_lat?,
+            long: self.center_long?,
+        })
+    }
+
+    /// Meters between `point` and the fence boundary, or 0 when inside.
+    pub fn distance_from(&self, point: &GeoLocation) -> f64 {
+        if let Some(polygon) = &self.polygon {
+            return polygon_distance(polygon, point);
+        }
+
+        match (self.center(), self.radius_meters) {
+            (Some(center), Some(radius)) => (center.distance_to(point) - radius).max(0.0),
+            _ => f64::INFINITY,
+        }
+    }
+}
+
+fn polygon_distance(polygon: &[GeoLocation], point: &GeoLocation) -> f64 {
+    if polygon.len() < 3 {
+        return f64::INFINITY;
+    }
+
+    let vertices: Vec<(f64, f64)> = polygon.iter().map(|v| point.offset_to(v)).collect();
+    let edges = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .take(vertices.len());
+
+    let mut inside = false;
+    let mut nearest = f64::INFINITY;
+    for (&(x1, y1), &(x2, y2)) in edges {
+        if (y1 > 0.0) != (y2 > 0.0) && 0.0 < x1 + (0.0 - y1) * (x2 - x1) / (y2 - y1) {
+            inside = !inside;
+        }
+        nearest = nearest.min(origin_to_segment(x1, y1, x2, y2));
+    }
+
+    if inside { 0.0 } else { nearest }
+}
+
+fn origin_to_segment(x1: f64, y1: f64, x2: f64, y2: f64) -> f64 {
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq == 0.0 {
+        0.0
+    } else {
+        (-(x1 * dx + y1 * dy) / length_sq).clamp(0.0, 1.0)
+    };
+    (x1 + t * dx).hypot(y1 + t * dy)
+}
+
+impl Default for Geofence {
+    fn default() -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            name: String::new(),
+            center_lat: None,
+            center_long: None,
+            radius_meters: None,
+            polygon: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct Holiday {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub date: NaiveDate,
+    pub recurring: bool,
+    pub source_uid: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Holiday {
+    pub fn falls_on(&self, day: NaiveDate) -> bool {
+        if self.recurring {
+            day >= self.date && day.month() == self.date.month() && day.day() == self.date.day()
+        } else {
+            day == self.date
+        }
+    }
+}
+
+impl Default for Holiday {
+    fn default() -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            name: String::new(),
+            date: now.date_naive(),
+            recurring: false,
+            source_uid: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
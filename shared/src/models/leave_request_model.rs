@@ -0,0 +1,55 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+use crate::types::models::leave::{leave_status::LeaveStatus, leave_type::LeaveType};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct LeaveRequest {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub leave_type: LeaveType,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub reason: String,
+    pub attachment_url: Option<String>,
+    pub status: LeaveStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LeaveRequest {
+    pub fn days(&self) -> i64 {
+        (self.end_date - self.start_date).num_days() + 1
+    }
+}
+
+impl Default for LeaveRequest {
+    fn default() -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            leave_type: LeaveType::default(),
+            start_date: now.date_naive(),
+            end_date: now.date_naive(),
+            reason: String::new(),
+            attachment_url: None,
+            status: LeaveStatus::default(),
+            reviewed_by: None,
+            reviewed_at: None,
+            review_note: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -1,3 +1,16 @@
+pub mod attendance_correction_model;
+pub mod attendance_export_model;
 pub mod attendance_model;
+pub mod attendance_summary_model;
+pub mod badge_model;
+pub mod device_model;
+pub mod geofence_model;
+pub mod group_model;
+pub mod holiday_model;
+pub mod leave_request_model;
 pub mod organization_model;
+pub mod refresh_token_model;
+pub mod shift_assignment_model;
+pub mod shift_model;
+pub mod subscription_model;
 pub mod user_model;
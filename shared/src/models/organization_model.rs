@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::types::models::attendance::geofence_policy::GeofencePolicy;
+
 #[cfg(feature = "backend")]
 use sqlx::FromRow;
 
@@ -15,6 +17,8 @@ pub struct Organization {
     pub logo_url: String,
     pub max_users: i32,
     pub max_attendance_logs: i32,
+    pub geofence_policy: GeofencePolicy,
+    pub timezone: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -30,6 +34,8 @@ impl Default for Organization {
             logo_url: String::new(),
             max_users: 0,
             max_attendance_logs: 0,
+            geofence_policy: GeofencePolicy::default(),
+            timezone: "UTC".to_string(),
             created_at: now,
             updated_at: now,
         }
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct ShiftAssignment {
+    pub id: Uuid,
+    pub shift_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
+
+use crate::types::models::attendance::attendance_status::AttendanceStatus;
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
+pub struct Shift {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub weekdays: Vec<i32>,
+    pub grace_minutes: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Shift {
+    pub fn duration_minutes(&self) -> i64 {
+        let minutes = (self.end_time - self.start_time).num_minutes();
+        if minutes <= 0 {
+            minutes + MINUTES_PER_DAY
+        } else {
+            minutes
+        }
+    }
+
+    pub fn status_for(&self, clock_in: NaiveTime) -> AttendanceStatus {
+        let mut since_start = (clock_in - self.start_time).num_minutes();
+        if since_start < -MINUTES_PER_DAY / 2 {
+            since_start += MINUTES_PER_DAY;
+        }
+
+        if since_start <= i64::from(self.grace_minutes) {
+            AttendanceStatus::Present
+        } else if since_start < self.duration_minutes() {
+            AttendanceStatus::Late
+        } else {
+            AttendanceStatus::Absent
+        }
+    }
+}
+
+impl Default for Shift {
+    fn default() -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            name: String::new(),
+            start_time: NaiveTime::MIN,
+            end_time: NaiveTime::MIN,
+            weekdays: Vec::new(),
+            grace_minutes: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
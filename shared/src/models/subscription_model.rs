@@ -1,63 +1,35 @@
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::{FromRow, Type};
-use strum_macros::Display;
 use uuid::Uuid;
 
-const PAID_SUBSCRIPTION_DURATION_DAYS: i64 = 30;
-const FREE_SUBSCRIPTION_DURATION_YEARS: i64 = 100;
-const GRACE_PERIOD_DAYS: i64 = 14;
-
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type, Display)]
-#[sqlx(type_name = "subscription_plan")]
-#[serde(rename_all = "lowercase")]
-#[strum(serialize_all = "lowercase")]
-pub enum SubscriptionPlan {
-    Free,
-    Pro,
-    Premium,
-    Enterprise,
-}
-
-impl Default for SubscriptionPlan {
-    fn default() -> Self {
-        Self::Free
-    }
-}
+use crate::types::models::subscription::{
+    subscription_plan::SubscriptionPlan, subscription_status::SubscriptionStatus,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type, Display)]
-#[sqlx(type_name = "subscription_status")]
-#[serde(rename_all = "lowercase")]
-#[strum(serialize_all = "lowercase")]
-pub enum SubscriptionStatus {
-    Active,
-    Expired,
-    Canceled,
-    GracePeriod,
-}
+#[cfg(feature = "backend")]
+use sqlx::FromRow;
 
-impl Default for SubscriptionStatus {
-    fn default() -> Self {
-        Self::Active
-    }
-}
+const PAID_SUBSCRIPTION_DURATION_DAYS: i64 = 30;
+const GRACE_PERIOD_DAYS: i64 = 14;
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(FromRow))]
 pub struct Subscription {
     pub id: Uuid,
     pub user_id: Uuid,
     pub plan: SubscriptionPlan,
     pub status: SubscriptionStatus,
     pub start_date: DateTime<Utc>,
-    pub expiry_date: DateTime<Utc>,
+    /// `None` for plans that never expire.
+    pub expiry_date: Option<DateTime<Utc>>,
 }
 
 impl Subscription {
     pub fn new(user_id: Uuid, plan: SubscriptionPlan) -> Self {
         let now = Utc::now();
         let expiry_date = match plan {
-            SubscriptionPlan::Free => now + Duration::days(365 * FREE_SUBSCRIPTION_DURATION_YEARS),
-            _ => now + Duration::days(PAID_SUBSCRIPTION_DURATION_DAYS),
+            SubscriptionPlan::Free => None,
+            _ => Some(now + Duration::days(PAID_SUBSCRIPTION_DURATION_DAYS)),
         };
 
         Self {
@@ -71,7 +43,8 @@ impl Subscription {
     }
 
     pub fn is_active(&self) -> bool {
-        self.status == SubscriptionStatus::Active && Utc::now() < self.expiry_date
+        self.status == SubscriptionStatus::Active
+            && self.expiry_date.is_none_or(|expiry| Utc::now() < expiry)
     }
 
     pub fn has_full_feature_access(&self) -> bool {
@@ -90,7 +63,7 @@ impl Subscription {
     }
 
     pub fn renew(&mut self, duration: Duration) {
-        self.expiry_date = self.expiry_date + duration;
+        self.expiry_date = Some(self.expiry_date.unwrap_or_else(Utc::now) + duration);
         self.status = SubscriptionStatus::Active;
     }
 
@@ -102,12 +75,15 @@ impl Subscription {
         if self.plan == SubscriptionPlan::Free {
             return;
         }
+        let Some(expiry_date) = self.expiry_date else {
+            return;
+        };
 
         let now = Utc::now();
-        let grace_end = self.expiry_date + Duration::days(GRACE_PERIOD_DAYS);
+        let grace_end = expiry_date + Duration::days(GRACE_PERIOD_DAYS);
 
         match self.status {
-            SubscriptionStatus::Active if now >= self.expiry_date => {
+            SubscriptionStatus::Active if now >= expiry_date => {
                 self.status = SubscriptionStatus::GracePeriod;
             }
             SubscriptionStatus::GracePeriod if now >= grace_end => {
@@ -7,6 +7,9 @@ use sqlx::FromRow;
 
 use crate::types::models::user::{role::Role, user_status::UserStatus};
 
+/// Organization of self-registered users until they register one of their own.
+pub const NO_ORGANIZATION: Uuid = Uuid::nil();
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "backend", derive(FromRow))]
 pub struct User {
@@ -16,6 +19,8 @@ pub struct User {
     pub organization_id: Uuid,
     pub role: Role,
     pub status: UserStatus,
+    #[serde(skip_serializing)]
+    pub password_hash: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -27,9 +32,10 @@ impl Default for User {
             id: Uuid::new_v4(),
             name: String::new(),
             email: String::new(),
-            organization_id: Uuid::new_v4(),
+            organization_id: NO_ORGANIZATION,
             role: Role::default(),
             status: UserStatus::default(),
+            password_hash: None,
             created_at: now,
             updated_at: now,
         }
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Debug, Deserialize)]
 pub struct Claims {
+    pub sub: String,
     pub name: String,
     pub email: String,
     pub exp: usize,
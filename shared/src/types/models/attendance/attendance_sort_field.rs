@@ -0,0 +1,12 @@
+use async_graphql::Enum;
+use serde::{Deserialize, Serialize};
+
+/// `Date` orders by the organization-local day the record belongs to.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Enum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttendanceSortField {
+    #[default]
+    Date,
+    ClockIn,
+    CreatedAt,
+}
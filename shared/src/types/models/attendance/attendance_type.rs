@@ -1,11 +1,15 @@
+use async_graphql::Enum;
 use serde::{Deserialize, Serialize};
 use strum_macros::Display;
 
-#[derive(Debug, Default, Clone, PartialEq, Display, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Enum, Display, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
 #[cfg_attr(feature = "backend", derive(sqlx::Type))]
-#[cfg_attr(feature = "backend", sqlx(type_name = "attendance_type"))]
+#[cfg_attr(
+    feature = "backend",
+    sqlx(type_name = "attendance_type", rename_all = "lowercase")
+)]
 pub enum AttendanceType {
     #[default]
     SingleMark,
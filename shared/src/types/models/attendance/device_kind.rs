@@ -0,0 +1,32 @@
+use async_graphql::Enum;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+#[cfg(feature = "backend")]
+use sqlx::Type;
+
+use crate::types::models::attendance::attendance_method::AttendanceMethod;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Enum, Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(Type))]
+#[cfg_attr(
+    feature = "backend",
+    sqlx(type_name = "device_kind", rename_all = "lowercase")
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum DeviceKind {
+    Kiosk,
+    #[default]
+    Nfcreader,
+    Biometric,
+}
+
+impl DeviceKind {
+    pub fn attendance_method(self) -> AttendanceMethod {
+        match self {
+            DeviceKind::Biometric => AttendanceMethod::Biometric,
+            DeviceKind::Kiosk | DeviceKind::Nfcreader => AttendanceMethod::Nfc,
+        }
+    }
+}
@@ -1,10 +1,35 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Validate)]
-#[cfg_attr(feature = "backend", derive(sqlx::Type))]
-#[cfg_attr(feature = "backend", sqlx(type_name = "geo_location"))]
 pub struct GeoLocation {
+    #[validate(range(min = -90.0, max = 90.0))]
     pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
     pub long: f64,
 }
+
+impl GeoLocation {
+    /// Great-circle distance in meters (haversine).
+    pub fn distance_to(&self, other: &GeoLocation) -> f64 {
+        let d_lat = (other.lat - self.lat).to_radians();
+        let d_long = (other.long - self.long).to_radians();
+        let a = (d_lat / 2.0).sin().powi(2)
+            + self.lat.to_radians().cos()
+                * other.lat.to_radians().cos()
+                * (d_long / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+    }
+
+    /// Projects `other` onto a local plane centered here, in meters east/north.
+    pub fn offset_to(&self, other: &GeoLocation) -> (f64, f64) {
+        let x = (other.long - self.long).to_radians()
+            * self.lat.to_radians().cos()
+            * EARTH_RADIUS_METERS;
+        let y = (other.lat - self.lat).to_radians() * EARTH_RADIUS_METERS;
+        (x, y)
+    }
+}
@@ -1,4 +1,10 @@
 pub mod attendance_method;
+pub mod attendance_sort_field;
 pub mod attendance_status;
 pub mod attendance_type;
+pub mod correction_status;
+pub mod device_kind;
+pub mod export_format;
+pub mod geofence_policy;
 pub mod geolocation;
+pub mod report_granularity;
@@ -0,0 +1,14 @@
+use async_graphql::Enum;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// Bucket size for attendance summaries; the lowercase name is the `date_trunc` field.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Enum, Display, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ReportGranularity {
+    #[default]
+    Day,
+    Week,
+    Month,
+}
@@ -0,0 +1,22 @@
+use async_graphql::Enum;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+#[cfg(feature = "backend")]
+use sqlx::Type;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Enum, Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(Type))]
+#[cfg_attr(
+    feature = "backend",
+    sqlx(type_name = "leave_status", rename_all = "lowercase")
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum LeaveStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+}
@@ -0,0 +1,2 @@
+pub mod leave_status;
+pub mod leave_type;
@@ -1,2 +1,6 @@
 pub mod attendance;
+pub mod leave;
+pub mod organization;
+pub mod sort_direction;
+pub mod subscription;
 pub mod user;
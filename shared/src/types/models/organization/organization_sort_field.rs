@@ -0,0 +1,10 @@
+use async_graphql::Enum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Enum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationSortField {
+    #[default]
+    Name,
+    CreatedAt,
+}
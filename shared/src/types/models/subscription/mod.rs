@@ -0,0 +1,2 @@
+pub mod subscription_plan;
+pub mod subscription_status;
@@ -1,3 +1,4 @@
 pub mod permissions;
 pub mod role;
+pub mod user_sort_field;
 pub mod user_status;
@@ -1,25 +1,74 @@
 use async_graphql::Enum;
 use serde::{Deserialize, Serialize};
-use strum_macros::Display;
+use strum_macros::{Display, EnumString};
+
+use super::permissions::Permission;
 
 #[cfg(feature = "backend")]
 use sqlx::Type;
 
-#[derive(Debug, Display, Copy, Enum, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Display, EnumString, Copy, Enum, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "backend", derive(Type))]
 #[cfg_attr(
     feature = "backend",
     sqlx(type_name = "role", rename_all = "lowercase")
 )]
 #[serde(rename_all = "lowercase")]
+#[strum(ascii_case_insensitive)]
 pub enum Role {
     Superadmin,
     Developer,
     Orgowner,
+    Admin,
+    Manager,
+    Member,
 }
 
 impl Default for Role {
     fn default() -> Self {
-        Self::Orgowner
+        Self::Member
+    }
+}
+
+impl Role {
+    pub fn permissions(self) -> &'static [Permission] {
+        match self {
+            Role::Superadmin | Role::Orgowner => &[
+                Permission::MarkAttendance,
+                Permission::ViewAttendance,
+                Permission::ManageAttendance,
+                Permission::ManageUsers,
+                Permission::ManageRoles,
+                Permission::ManageOrganization,
+            ],
+            // Role assignment stays with org owners.
+            Role::Developer => &[
+                Permission::MarkAttendance,
+                Permission::ViewAttendance,
+                Permission::ManageAttendance,
+                Permission::ManageUsers,
+                Permission::ManageOrganization,
+            ],
+            Role::Admin => &[
+                Permission::MarkAttendance,
+                Permission::ViewAttendance,
+                Permission::ManageAttendance,
+                Permission::ManageUsers,
+            ],
+            Role::Manager => &[
+                Permission::MarkAttendance,
+                Permission::ViewAttendance,
+                Permission::ManageAttendance,
+            ],
+            Role::Member => &[Permission::MarkAttendance, Permission::ViewAttendance],
+        }
+    }
+
+    pub fn has_permission(self, permission: Permission) -> bool {
+        self.permissions().contains(&permission)
+    }
+
+    pub fn is_assignable(self) -> bool {
+        matches!(self, Role::Admin | Role::Manager | Role::Member)
     }
 }
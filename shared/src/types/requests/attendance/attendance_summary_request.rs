@@ -0,0 +1,11 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::attendance::report_granularity::ReportGranularity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttendanceSummaryRequest {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub group_by: ReportGranularity,
+}
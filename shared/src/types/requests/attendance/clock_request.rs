@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::attendance::attendance_method::AttendanceMethod;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ClockRequest {
+    pub method: Option<AttendanceMethod>,
+    pub lat: Option<f64>,
+    pub long: Option<f64>,
+}
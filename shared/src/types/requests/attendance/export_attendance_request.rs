@@ -0,0 +1,14 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::attendance::export_format::ExportFormat;
+
+/// Query string of the attendance export endpoint; the organization defaults to the caller's.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportAttendanceRequest {
+    pub organization_id: Option<String>,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    #[serde(default)]
+    pub format: ExportFormat,
+}
@@ -0,0 +1,23 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::{
+    attendance::{
+        attendance_method::AttendanceMethod, attendance_sort_field::AttendanceSortField,
+        attendance_status::AttendanceStatus,
+    },
+    sort_direction::SortDirection,
+};
+
+/// Dates are the organization-local days, both ends inclusive.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ListAttendancesRequest {
+    pub organization_id: Option<String>,
+    pub user_id: Option<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub status: Option<AttendanceStatus>,
+    pub method: Option<AttendanceMethod>,
+    pub sort_by: AttendanceSortField,
+    pub direction: SortDirection,
+}
@@ -1,2 +1,8 @@
+pub mod attendance_summary_request;
+pub mod clock_request;
+pub mod export_attendance_request;
+pub mod list_attendances_request;
+pub mod qr_check_in_request;
 pub mod register_attendance_request;
-pub mod update_attendance_request;
+pub mod review_correction_request;
+pub mod submit_correction_request;
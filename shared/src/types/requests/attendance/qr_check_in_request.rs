@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct QrCheckInRequest {
+    pub code: String,
+    pub lat: Option<f64>,
+    pub long: Option<f64>,
+}
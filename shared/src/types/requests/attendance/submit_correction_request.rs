@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::attendance::attendance_status::AttendanceStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitCorrectionRequest {
+    pub attendance_id: String,
+    pub clock_in: Option<DateTime<Utc>>,
+    pub clock_out: Option<DateTime<Utc>>,
+    pub status: Option<AttendanceStatus>,
+    pub reason: String,
+}
@@ -6,4 +6,5 @@ pub struct RegisterRequest {
     pub id: Option<String>,
     pub name: String,
     pub email: String,
+    pub password: Option<String>,
 }
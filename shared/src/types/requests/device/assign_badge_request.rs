@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignBadgeRequest {
+    pub user_id: String,
+    pub uid: String,
+}
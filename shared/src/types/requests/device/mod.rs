@@ -0,0 +1,3 @@
+pub mod assign_badge_request;
+pub mod device_check_in_request;
+pub mod register_device_request;
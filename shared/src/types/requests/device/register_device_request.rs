@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::attendance::device_kind::DeviceKind;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterDeviceRequest {
+    pub name: String,
+    pub kind: DeviceKind,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::attendance::geolocation::GeoLocation;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeofenceRequest {
+    pub name: String,
+    pub center: Option<GeoLocation>,
+    pub radius_meters: Option<f64>,
+    pub polygon: Option<Vec<GeoLocation>>,
+}
@@ -0,0 +1 @@
+pub mod geofence_request;
@@ -0,0 +1,2 @@
+pub mod review_leave_request;
+pub mod submit_leave_request;
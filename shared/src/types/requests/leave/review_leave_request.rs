@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewLeaveRequest {
+    pub approve: bool,
+    pub note: Option<String>,
+}
@@ -0,0 +1,13 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::leave::leave_type::LeaveType;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitLeaveRequest {
+    pub leave_type: LeaveType,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub reason: String,
+    pub attachment_url: Option<String>,
+}
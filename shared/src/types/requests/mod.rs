@@ -1,4 +1,9 @@
 pub mod attendance;
 pub mod auth;
+pub mod device;
+pub mod geofence;
+pub mod leave;
 pub mod organization;
+pub mod page_request;
+pub mod schedule;
 pub mod user;
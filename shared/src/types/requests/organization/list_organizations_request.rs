@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::{
+    organization::organization_sort_field::OrganizationSortField, sort_direction::SortDirection,
+};
+
+/// `search` matches a substring of the name or email, case-insensitively.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ListOrganizationsRequest {
+    pub search: Option<String>,
+    pub sort_by: OrganizationSortField,
+    pub direction: SortDirection,
+}
@@ -1,2 +1,3 @@
+pub mod list_organizations_request;
 pub mod register_organization_request;
 pub mod update_organization_request;
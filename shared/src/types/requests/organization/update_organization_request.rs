@@ -1,9 +1,14 @@
 use serde::Deserialize;
 use validator::Validate;
 
+use crate::types::models::attendance::geofence_policy::GeofencePolicy;
+
 #[derive(Debug, Clone, Deserialize, Validate)]
+/// Fields left as `None` keep their stored value.
 pub struct UpdateOrganizationRequest {
-    pub email: String,
-    pub name: String,
-    pub logo_url: String,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub logo_url: Option<String>,
+    pub geofence_policy: Option<GeofencePolicy>,
+    pub timezone: Option<String>,
 }
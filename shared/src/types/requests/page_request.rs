@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::types::responses::page_response::PageResponse;
+
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// Position of a row in a sorted list: its sort column rendered as text (`None` for NULL) and
+/// its id as the tiebreaker. Pages continue from the row itself, so inserts elsewhere in the
+/// list don't shift them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCursor {
+    pub key: Option<String>,
+    pub id: Uuid,
+}
+
+/// Relay-style window arguments. `count_total` is only set when the caller asked for the total,
+/// since counting scans every matching row.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageRequest {
+    pub after: Option<PageCursor>,
+    pub before: Option<PageCursor>,
+    pub first: Option<usize>,
+    pub last: Option<usize>,
+    pub count_total: bool,
+}
+
+impl PageRequest {
+    /// `last` without `first` reads the list from its end, or from `before`.
+    pub fn backwards(&self) -> bool {
+        self.first.is_none() && self.last.is_some()
+    }
+
+    /// Page size, capped at `MAX_PAGE_SIZE`; `DEFAULT_PAGE_SIZE` applies when neither `first`
+    /// nor `last` is set. Repositories fetch one row more to tell whether another page follows.
+    pub fn limit(&self) -> usize {
+        self.first
+            .or(self.last)
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .min(MAX_PAGE_SIZE)
+    }
+
+    /// Turns the `limit() + 1` rows a repository fetched, in reading order, into a page.
+    pub fn into_page<T>(
+        &self,
+        mut rows: Vec<T>,
+        cursor: impl Fn(&T) -> PageCursor,
+        total_count: Option<usize>,
+    ) -> PageResponse<T> {
+        let limit = self.limit();
+        let more = rows.len() > limit;
+        rows.truncate(limit);
+
+        let (mut has_previous_page, has_next_page) = if self.backwards() {
+            rows.reverse();
+            (more, self.before.is_some())
+        } else {
+            (self.after.is_some(), more)
+        };
+
+        if let (Some(_), Some(last)) = (self.first, self.last) {
+            let last = last.min(MAX_PAGE_SIZE);
+            if rows.len() > last {
+                rows.drain(..rows.len() - last);
+                has_previous_page = true;
+            }
+        }
+
+        PageResponse {
+            items: rows.into_iter().map(|row| (cursor(&row), row)).collect(),
+            has_previous_page,
+            has_next_page,
+            total_count,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignShiftRequest {
+    pub shift_id: String,
+    pub user_id: Option<String>,
+    pub group_id: Option<String>,
+}
@@ -0,0 +1,9 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayRequest {
+    pub name: String,
+    pub date: NaiveDate,
+    pub recurring: bool,
+}
@@ -0,0 +1,3 @@
+pub mod assign_shift_request;
+pub mod holiday_request;
+pub mod shift_request;
@@ -0,0 +1,11 @@
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftRequest {
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub weekdays: Vec<i32>,
+    pub grace_minutes: i32,
+}
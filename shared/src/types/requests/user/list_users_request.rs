@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::models::{
+    sort_direction::SortDirection,
+    user::{role::Role, user_sort_field::UserSortField, user_status::UserStatus},
+};
+
+/// `search` matches a substring of the name or email, case-insensitively.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ListUsersRequest {
+    pub role: Option<Role>,
+    pub status: Option<UserStatus>,
+    pub search: Option<String>,
+    pub sort_by: UserSortField,
+    pub direction: SortDirection,
+}
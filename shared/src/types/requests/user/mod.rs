@@ -1 +1,2 @@
+pub mod list_users_request;
 pub mod update_user_request;
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::attendance_correction_model::AttendanceCorrection,
+    types::models::attendance::{
+        attendance_status::AttendanceStatus, correction_status::CorrectionStatus,
+    },
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AttendanceCorrectionResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub attendance_id: String,
+    pub requested_by: String,
+    pub original_clock_in: Option<DateTime<Utc>>,
+    pub original_clock_out: Option<DateTime<Utc>>,
+    pub original_status: AttendanceStatus,
+    pub proposed_clock_in: Option<DateTime<Utc>>,
+    pub proposed_clock_out: Option<DateTime<Utc>>,
+    pub proposed_status: Option<AttendanceStatus>,
+    pub reason: String,
+    pub status: CorrectionStatus,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AttendanceCorrection> for AttendanceCorrectionResponse {
+    fn from(c: AttendanceCorrection) -> Self {
+        Self {
+            id: c.id.to_string(),
+            organization_id: c.organization_id.to_string(),
+            attendance_id: c.attendance_id.to_string(),
+            requested_by: c.requested_by.to_string(),
+            original_clock_in: c.original_clock_in,
+            original_clock_out: c.original_clock_out,
+            original_status: c.original_status,
+            proposed_clock_in: c.proposed_clock_in,
+            proposed_clock_out: c.proposed_clock_out,
+            proposed_status: c.proposed_status,
+            reason: c.reason,
+            status: c.status,
+            reviewed_by: c.reviewed_by.map(|id| id.to_string()),
+            reviewed_at: c.reviewed_at,
+            review_note: c.review_note,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -16,11 +16,16 @@ pub struct AttendanceResponse {
     pub organization_id: String,
     pub attendance_type: AttendanceType,
     pub status: AttendanceStatus,
+    pub date: DateTime<Utc>,
+    pub local_date: NaiveDate,
     pub clock_in: Option<DateTime<Utc>>,
     pub clock_out: Option<DateTime<Utc>>,
+    pub worked_minutes: Option<i64>,
     pub method: AttendanceMethod,
     pub lat: Option<f64>,
     pub long: Option<f64>,
+    pub distance_meters: Option<f64>,
+    pub outside_geofence: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -33,11 +38,16 @@ impl From<Attendance> for AttendanceResponse {
             organization_id: attendance.organization_id.to_string(),
             attendance_type: attendance.attendance_type,
             status: attendance.status,
+            date: attendance.date,
+            local_date: attendance.local_date,
             clock_in: attendance.clock_in,
             clock_out: attendance.clock_out,
+            worked_minutes: attendance.worked_minutes(),
             method: attendance.method,
             lat: attendance.lat,
             long: attendance.long,
+            distance_meters: attendance.distance_meters,
+            outside_geofence: attendance.outside_geofence,
             created_at: attendance.created_at,
             updated_at: attendance.updated_at,
         }
@@ -0,0 +1,33 @@
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use crate::models::attendance_summary_model::AttendanceSummary;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AttendanceSummaryResponse {
+    pub period_start: NaiveDate,
+    pub user_id: Option<String>,
+    pub present: i64,
+    pub late: i64,
+    pub absent: i64,
+    pub excused: i64,
+    pub attendance_rate: Option<f64>,
+    pub average_clock_in: Option<NaiveTime>,
+    pub total_hours: f64,
+}
+
+impl From<AttendanceSummary> for AttendanceSummaryResponse {
+    fn from(s: AttendanceSummary) -> Self {
+        Self {
+            period_start: s.period_start,
+            user_id: s.user_id.map(|id| id.to_string()),
+            present: s.present,
+            late: s.late,
+            absent: s.absent,
+            excused: s.excused,
+            attendance_rate: s.attendance_rate,
+            average_clock_in: s.average_clock_in,
+            total_hours: s.total_hours,
+        }
+    }
+}
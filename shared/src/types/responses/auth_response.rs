@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::responses::user_response::UserResponse;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AuthResponse {
+    pub token: String,
+    pub refresh_token: String,
+    pub user: UserResponse,
+}
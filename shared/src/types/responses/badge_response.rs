@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::badge_model::Badge;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct BadgeResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub user_id: String,
+    pub uid: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Badge> for BadgeResponse {
+    fn from(badge: Badge) -> Self {
+        Self {
+            id: badge.id.to_string(),
+            organization_id: badge.organization_id.to_string(),
+            user_id: badge.user_id.to_string(),
+            uid: badge.uid,
+            created_at: badge.created_at,
+        }
+    }
+}
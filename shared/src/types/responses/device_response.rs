@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{models::device_model::Device, types::models::attendance::device_kind::DeviceKind};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DeviceResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub name: String,
+    pub kind: DeviceKind,
+    pub last_seen_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Device> for DeviceResponse {
+    fn from(device: Device) -> Self {
+        Self {
+            id: device.id.to_string(),
+            organization_id: device.organization_id.to_string(),
+            name: device.name,
+            kind: device.kind,
+            last_seen_at: device.last_seen_at,
+            revoked_at: device.revoked_at,
+            created_at: device.created_at,
+            updated_at: device.updated_at,
+        }
+    }
+}
+
+/// Returned once at registration; the plain API key is never stored.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct RegisteredDeviceResponse {
+    pub device: DeviceResponse,
+    pub api_key: String,
+}
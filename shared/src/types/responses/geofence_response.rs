@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::geofence_model::Geofence, types::models::attendance::geolocation::GeoLocation,
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct GeofenceResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub name: String,
+    pub center: Option<GeoLocation>,
+    pub radius_meters: Option<f64>,
+    pub polygon: Option<Vec<GeoLocation>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Geofence> for GeofenceResponse {
+    fn from(geofence: Geofence) -> Self {
+        let center = geofence.center();
+        Self {
+            id: geofence.id.to_string(),
+            organization_id: geofence.organization_id.to_string(),
+            name: geofence.name,
+            center,
+            radius_meters: geofence.radius_meters,
+            polygon: geofence.polygon,
+            created_at: geofence.created_at,
+            updated_at: geofence.updated_at,
+        }
+    }
+}
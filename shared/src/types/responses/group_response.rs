@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::group_model::Group;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct GroupResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Group> for GroupResponse {
+    fn from(group: Group) -> Self {
+        Self {
+            id: group.id.to_string(),
+            organization_id: group.organization_id.to_string(),
+            name: group.name,
+            created_at: group.created_at,
+            updated_at: group.updated_at,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::responses::holiday_response::HolidayResponse;
+
+/// A calendar event left out of the import, with the reason in the caller's language.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct SkippedHolidayEvent {
+    pub uid: Option<String>,
+    pub name: String,
+    pub rrule: String,
+    pub reason: String,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct HolidayImportResponse {
+    pub holidays: Vec<HolidayResponse>,
+    pub skipped: Vec<SkippedHolidayEvent>,
+}
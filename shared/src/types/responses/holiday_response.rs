@@ -0,0 +1,29 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::holiday_model::Holiday;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct HolidayResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub name: String,
+    pub date: NaiveDate,
+    pub recurring: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Holiday> for HolidayResponse {
+    fn from(h: Holiday) -> Self {
+        Self {
+            id: h.id.to_string(),
+            organization_id: h.organization_id.to_string(),
+            name: h.name,
+            date: h.date,
+            recurring: h.recurring,
+            created_at: h.created_at,
+            updated_at: h.updated_at,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::leave_request_model::LeaveRequest,
+    types::models::leave::{leave_status::LeaveStatus, leave_type::LeaveType},
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct LeaveRequestResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub user_id: String,
+    pub leave_type: LeaveType,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub days: i64,
+    pub reason: String,
+    pub attachment_url: Option<String>,
+    pub status: LeaveStatus,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<LeaveRequest> for LeaveRequestResponse {
+    fn from(leave: LeaveRequest) -> Self {
+        Self {
+            id: leave.id.to_string(),
+            organization_id: leave.organization_id.to_string(),
+            user_id: leave.user_id.to_string(),
+            leave_type: leave.leave_type,
+            start_date: leave.start_date,
+            end_date: leave.end_date,
+            days: leave.days(),
+            reason: leave.reason,
+            attachment_url: leave.attachment_url,
+            status: leave.status,
+            reviewed_by: leave.reviewed_by.map(|id| id.to_string()),
+            reviewed_at: leave.reviewed_at,
+            review_note: leave.review_note,
+            created_at: leave.created_at,
+            updated_at: leave.updated_at,
+        }
+    }
+}
@@ -1,4 +1,20 @@
 pub mod api_response;
+pub mod attendance_correction_response;
 pub mod attendance_response;
+pub mod attendance_summary_response;
+pub mod auth_response;
+pub mod badge_response;
+pub mod device_response;
+pub mod geofence_response;
+pub mod group_response;
+pub mod holiday_import_response;
+pub mod holiday_response;
+pub mod leave_request_response;
 pub mod organization_response;
+pub mod page_response;
+pub mod qr_code_response;
+pub mod shift_assignment_response;
+pub mod shift_response;
+pub mod subscription_response;
+pub mod user_import_response;
 pub mod user_response;
@@ -1,7 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::models::organization_model::Organization;
+use crate::{
+    models::organization_model::Organization,
+    types::models::attendance::geofence_policy::GeofencePolicy,
+};
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct OrganizationResponse {
@@ -12,6 +15,8 @@ pub struct OrganizationResponse {
     pub max_attendance_logs: i32,
     pub owner_id: String,
     pub logo_url: String,
+    pub geofence_policy: GeofencePolicy,
+    pub timezone: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -26,6 +31,8 @@ impl From<Organization> for OrganizationResponse {
             email: organization.email,
             owner_id: organization.owner_id.to_string(),
             logo_url: organization.logo_url,
+            geofence_policy: organization.geofence_policy,
+            timezone: organization.timezone,
             created_at: organization.created_at,
             updated_at: organization.updated_at,
         }
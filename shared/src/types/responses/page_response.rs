@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::requests::page_request::PageCursor;
+
+/// One window of a sorted list, each item with its cursor. `total_count` is only filled when
+/// the request asked for it.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PageResponse<T> {
+    pub items: Vec<(PageCursor, T)>,
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+    pub total_count: Option<usize>,
+}
+
+impl<T> PageResponse<T> {
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> PageResponse<U> {
+        PageResponse {
+            items: self
+                .items
+                .into_iter()
+                .map(|(cursor, item)| (cursor, f(item)))
+                .collect(),
+            has_previous_page: self.has_previous_page,
+            has_next_page: self.has_next_page,
+            total_count: self.total_count,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct QrCodeResponse {
+    pub code: String,
+    pub expires_at: DateTime<Utc>,
+    pub rotation_seconds: i64,
+}
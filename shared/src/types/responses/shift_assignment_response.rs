@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::shift_assignment_model::ShiftAssignment;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ShiftAssignmentResponse {
+    pub id: String,
+    pub shift_id: String,
+    pub user_id: Option<String>,
+    pub group_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ShiftAssignment> for ShiftAssignmentResponse {
+    fn from(assignment: ShiftAssignment) -> Self {
+        Self {
+            id: assignment.id.to_string(),
+            shift_id: assignment.shift_id.to_string(),
+            user_id: assignment.user_id.map(|id| id.to_string()),
+            group_id: assignment.group_id.map(|id| id.to_string()),
+            created_at: assignment.created_at,
+        }
+    }
+}
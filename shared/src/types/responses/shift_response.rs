@@ -0,0 +1,33 @@
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::shift_model::Shift;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ShiftResponse {
+    pub id: String,
+    pub organization_id: String,
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub weekdays: Vec<i32>,
+    pub grace_minutes: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Shift> for ShiftResponse {
+    fn from(shift: Shift) -> Self {
+        Self {
+            id: shift.id.to_string(),
+            organization_id: shift.organization_id.to_string(),
+            name: shift.name,
+            start_time: shift.start_time,
+            end_time: shift.end_time,
+            weekdays: shift.weekdays,
+            grace_minutes: shift.grace_minutes,
+            created_at: shift.created_at,
+            updated_at: shift.updated_at,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::subscription_model::Subscription,
+    types::models::subscription::{
+        subscription_plan::SubscriptionPlan, subscription_status::SubscriptionStatus,
+    },
+};
+
+/// `read_access` and `write_access` are evaluated when the response is built.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct SubscriptionResponse {
+    pub id: String,
+    pub user_id: String,
+    pub plan: SubscriptionPlan,
+    pub status: SubscriptionStatus,
+    pub start_date: DateTime<Utc>,
+    pub expiry_date: Option<DateTime<Utc>>,
+    pub read_access: bool,
+    pub write_access: bool,
+}
+
+impl From<Subscription> for SubscriptionResponse {
+    fn from(subscription: Subscription) -> Self {
+        Self {
+            id: subscription.id.to_string(),
+            user_id: subscription.user_id.to_string(),
+            read_access: subscription.allows_read_access(),
+            write_access: subscription.allows_write_access(),
+            plan: subscription.plan,
+            status: subscription.status,
+            start_date: subscription.start_date,
+            expiry_date: subscription.expiry_date,
+        }
+    }
+}
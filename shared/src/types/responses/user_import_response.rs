@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::responses::user_response::UserResponse;
+
+/// `row` is the file line the offending record starts on, with the header on line 1.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct UserImportRowError {
+    pub row: usize,
+    pub email: Option<String>,
+    pub field: String,
+    pub message: String,
+}
+
+/// In a dry run `users` lists the accounts that would be created, without ids being persisted.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct UserImportResponse {
+    pub dry_run: bool,
+    pub total_rows: usize,
+    pub users: Vec<UserResponse>,
+    pub errors: Vec<UserImportRowError>,
+}
@@ -2,10 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::{
-    requests::attendance::{
-        register_attendance_request::RegisterAttendanceRequest,
-        update_attendance_request::UpdateAttendanceRequest,
-    },
+    requests::attendance::register_attendance_request::RegisterAttendanceRequest,
     responses::api_response::ApiResponse,
 };
 
@@ -15,10 +12,6 @@ pub enum AttendanceWsMessage {
     Create(RegisterAttendanceRequest),
     Read(String),
     ReadAll,
-    Update {
-        id: String,
-        payload: UpdateAttendanceRequest,
-    },
     Delete(String),
 }
 
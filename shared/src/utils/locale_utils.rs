@@ -45,6 +45,9 @@ pub enum Namespace {
     Common,
     Organization,
     Attendance,
+    Schedule,
+    Device,
+    Leave,
 }
 
 impl fmt::Display for Namespace {
@@ -59,6 +62,9 @@ impl fmt::Display for Namespace {
                 Namespace::Common => "common",
                 Namespace::Organization => "organization",
                 Namespace::Attendance => "attendance",
+                Namespace::Schedule => "schedule",
+                Namespace::Device => "device",
+                Namespace::Leave => "leave",
             }
         )
     }
@@ -76,7 +82,7 @@ pub enum MessageError {
     InvalidType { namespace: Namespace, path: String },
 }
 
-fn default_namespaces() -> [Namespace; 6] {
+fn default_namespaces() -> [Namespace; 9] {
     [
         Namespace::User,
         Namespace::Validation,
@@ -84,6 +90,9 @@ fn default_namespaces() -> [Namespace; 6] {
         Namespace::Common,
         Namespace::Organization,
         Namespace::Attendance,
+        Namespace::Schedule,
+        Namespace::Device,
+        Namespace::Leave,
     ]
 }
 